@@ -0,0 +1,232 @@
+// just enough JSON to read cargo's build messages, the crate has no dependencies
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // parses a whole document, `None` if it is not valid JSON
+    pub fn parse(text: &str) -> Option<Json> {
+        let mut parser = Parser {
+            chars: text.chars().collect(),
+            position: 0,
+        };
+
+        let value = parser.value()?;
+        parser.whitespace();
+        (parser.position == parser.chars.len()).then_some(value)
+    }
+
+    // the value of an object's key
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(string) => Some(string),
+            _ => None,
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Option<()> {
+        for expected in literal.chars() {
+            (self.next()? == expected).then_some(())?;
+        }
+        Some(())
+    }
+
+    fn value(&mut self) -> Option<Json> {
+        self.whitespace();
+        match self.peek()? {
+            'n' => self.expect("null").map(|_| Json::Null),
+            't' => self.expect("true").map(|_| Json::Bool(true)),
+            'f' => self.expect("false").map(|_| Json::Bool(false)),
+            '"' => self.string().map(Json::String),
+            '[' => self.array(),
+            '{' => self.object(),
+            _ => self.number(),
+        }
+    }
+
+    fn number(&mut self) -> Option<Json> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+        {
+            self.position += 1;
+        }
+
+        let number = self.chars[start..self.position].iter().collect::<String>();
+        number.parse().ok().map(Json::Number)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect("\"")?;
+        let mut string = String::new();
+
+        loop {
+            match self.next()? {
+                '"' => return Some(string),
+                '\\' => match self.next()? {
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'u' => string.push(self.escaped_char()?),
+                    c @ ('"' | '\\' | '/') => string.push(c),
+                    _ => return None,
+                },
+                c => string.push(c),
+            }
+        }
+    }
+
+    // the code after `\u`, characters outside the basic plane come as a surrogate pair
+    fn escaped_char(&mut self) -> Option<char> {
+        let high = self.hex()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high);
+        }
+
+        self.expect("\\u")?;
+        let low = self.hex()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return None;
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+    }
+
+    fn hex(&mut self) -> Option<u32> {
+        (0..4).try_fold(0, |code, _| Some(code * 16 + self.next()?.to_digit(16)?))
+    }
+
+    fn array(&mut self) -> Option<Json> {
+        self.expect("[")?;
+        let mut items = Vec::new();
+
+        self.whitespace();
+        if self.peek()? == ']' {
+            self.position += 1;
+            return Some(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Some(Json::Array(items)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn object(&mut self) -> Option<Json> {
+        self.expect("{")?;
+        let mut entries = Vec::new();
+
+        self.whitespace();
+        if self.peek()? == '}' {
+            self.position += 1;
+            return Some(Json::Object(entries));
+        }
+
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.whitespace();
+            self.expect(":")?;
+            entries.push((key, self.value()?));
+            self.whitespace();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Some(Json::Object(entries)),
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn artifact_message() {
+        let message = r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"day05"},"profile":{"opt_level":"3","debug_assertions":false},"filenames":["/tmp/a \"b\"/day05"],"executable":"/tmp/a \"b\"/day05","fresh":true}"#;
+        let json = Json::parse(message).unwrap();
+
+        assert_eq!(
+            json.get("reason").and_then(Json::as_str),
+            Some("compiler-artifact")
+        );
+        assert_eq!(
+            json.get("executable").and_then(Json::as_str),
+            Some("/tmp/a \"b\"/day05")
+        );
+        assert_eq!(json.get("missing"), None);
+    }
+
+    #[test]
+    fn escapes() {
+        let json = Json::parse(r#""C:\\aoc\\day05.exe \u00e9 \ud83e\udd80 \/""#).unwrap();
+        assert_eq!(json.as_str(), Some("C:\\aoc\\day05.exe é 🦀 /"));
+    }
+
+    #[test]
+    fn values() {
+        let json = Json::parse(r#" {"a": [1, -2.5e1, true, null], "b": {}} "#).unwrap();
+        assert_eq!(
+            json.get("a"),
+            Some(&Json::Array(vec![
+                Json::Number(1.0),
+                Json::Number(-25.0),
+                Json::Bool(true),
+                Json::Null,
+            ]))
+        );
+        assert_eq!(json.get("b"), Some(&Json::Object(Vec::new())));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Json::parse(r#"{"executable":"/tmp/day05"#), None);
+        assert_eq!(Json::parse(r#"{"a":1} trailing"#), None);
+        assert_eq!(Json::parse(r#""\x""#), None);
+    }
+}
@@ -0,0 +1,15 @@
+// helpers shared by the daily solutions
+//
+// the crate builds without `std` (`--no-default-features`) so the solutions
+// can be embedded in hosts without a filesystem, e.g. `wasm32-unknown-unknown`
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod minimize;
+pub mod params;
+mod solution;
+pub mod step;
+
+pub use solution::{Answer, Error, Part};
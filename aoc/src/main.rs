@@ -0,0 +1,205 @@
+use aoc::minimize::{minimize, Unit};
+use aoc::params::Params;
+use json::Json;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+mod json;
+
+// `aoc minimize --day N input [--output path] [--expect text] [-- day arguments]`
+//
+// shrinks an input that makes a solver fail, keeping only what is needed to reproduce the failure
+// the days depend on this crate, so their binaries are run as separate processes
+//
+// a failure is a panic or an error exit status with the same errors, `--expect text` instead
+// looks for the text in the output
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: aoc minimize --day N <input> [--output path] [--expect text] [-- day arguments]"
+    );
+    process::exit(2);
+}
+
+fn unit_for_day(day: u32) -> Unit {
+    match day {
+        // monkeys and packet pairs
+        11 | 13 => Unit::Block,
+        _ => Unit::Line,
+    }
+}
+
+// builds the day's binary and returns its path
+fn build_day(day: u32) -> Result<PathBuf, String> {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+        .join("Cargo.toml");
+    if !manifest.exists() {
+        return Err(format!("There is no solution for day {day}!"));
+    }
+
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["build", "--release", "--quiet", "--message-format=json"])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .output()
+        .map_err(|e| format!("Could not run cargo: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Could not build day {day}:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // every line is a JSON message, the binary is the artifact with an executable
+    let messages = String::from_utf8_lossy(&output.stdout);
+    messages
+        .lines()
+        .filter_map(Json::parse)
+        .filter(|message| message.get("reason").and_then(Json::as_str) == Some("compiler-artifact"))
+        .filter_map(|message| message.get("executable")?.as_str().map(PathBuf::from))
+        .next_back()
+        .ok_or_else(|| format!("Could not find the binary of day {day}!"))
+}
+
+// error messages point at input lines, which move as lines are removed
+fn without_line_numbers(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+
+    while let Some(i) = rest.find("line ") {
+        let (before, after) = rest.split_at(i + "line ".len());
+        let number = after.len() - after.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+        result.push_str(before);
+        if number > 0 {
+            result.push('N');
+        }
+        rest = &after[number..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+// what identifies a failure - the panic location and message, or the exit status and the errors
+fn failure(binary: &Path, input: &Path, arguments: &[String], expect: &str) -> Option<String> {
+    let output = Command::new(binary)
+        .arg(input)
+        .args(arguments)
+        // keeps the panic output stable
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .expect("Could not run the solution!");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !expect.is_empty() {
+        let found = stdout.contains(expect) || stderr.contains(expect);
+        return found.then(|| expect.to_string());
+    }
+    if output.status.success() {
+        return None;
+    }
+
+    // the answers on stdout change with the input, the days print their errors on stderr
+    let Some(start) = stderr.find("panicked at") else {
+        let errors = without_line_numbers(stderr.trim_end());
+        return Some(format!("{}\n{errors}", output.status));
+    };
+
+    // the thread name (and id) before `panicked at` is left out
+    let panic = stderr[start..]
+        .lines()
+        .take_while(|line| !line.starts_with("note:"))
+        .collect::<Vec<&str>>();
+    Some(panic.join("\n"))
+}
+
+fn main() {
+    // everything after `--` is passed on to the day, e.g. its `--config`
+    let mut args = env::args().collect::<Vec<String>>();
+    let arguments = match args.iter().position(|arg| arg == "--") {
+        Some(separator) => args.drain(separator..).skip(1).collect(),
+        None => Vec::new(),
+    };
+
+    let mut params = Params::from_args(args).unwrap_or_else(|e| panic!("{e}"));
+    let day: u32 = params.take("day", 0).unwrap_or_else(|e| panic!("{e}"));
+    // an empty path prints the result instead
+    let output_path: String = params
+        .take("output", String::new())
+        .unwrap_or_else(|e| panic!("{e}"));
+    // the text that marks a failure in the day's output, empty for panics and error exits
+    let expect: String = params
+        .take("expect", String::new())
+        .unwrap_or_else(|e| panic!("{e}"));
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
+
+    let [_, command, input_path] = &args[..] else {
+        usage();
+    };
+    if command != "minimize" || day == 0 {
+        usage();
+    }
+
+    let content = fs::read_to_string(input_path).expect("Could not read the input file!");
+    let binary = build_day(day).unwrap_or_else(|e| panic!("{e}"));
+
+    let candidate_path = env::temp_dir().join(format!("aoc-minimize-{}.txt", process::id()));
+    let unit = unit_for_day(day);
+
+    fs::write(&candidate_path, &content).expect("Could not write the candidate input!");
+    let Some(expected) = failure(&binary, &candidate_path, &arguments, &expect) else {
+        eprintln!("Day {day} does not fail on {input_path}, there is nothing to minimize.");
+        process::exit(1);
+    };
+    eprintln!("reproducing: {expected}");
+
+    let units = unit.split(&content);
+    let original_count = units.len();
+    let mut runs = 0;
+
+    let minimal = minimize(units, |candidate| {
+        runs += 1;
+        fs::write(&candidate_path, unit.join(candidate))
+            .expect("Could not write the candidate input!");
+        failure(&binary, &candidate_path, &arguments, &expect).as_ref() == Some(&expected)
+    });
+
+    // best effort, the file lives in the temp dir anyway
+    let _ = fs::remove_file(&candidate_path);
+
+    eprintln!(
+        "kept {} of {original_count} {} after {runs} runs",
+        minimal.len(),
+        match unit {
+            Unit::Line => "lines",
+            Unit::Block => "blocks",
+        }
+    );
+
+    let result = unit.join(&minimal);
+    if output_path.is_empty() {
+        print!("{result}");
+    } else {
+        fs::write(output_path, result).expect("Could not write the output file!");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_line_numbers() {
+        assert_eq!(
+            without_line_numbers("part 1: invalid input on line 14: bad line 2x\npart 2: 42"),
+            "part 1: invalid input on line N: bad line Nx\npart 2: 42"
+        );
+        assert_eq!(without_line_numbers("no line here"), "no line here");
+    }
+}
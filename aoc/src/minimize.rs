@@ -0,0 +1,68 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// the pieces an input is shrunk by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    // single lines, e.g. sensors or cubes
+    Line,
+    // blocks separated by an empty line, e.g. monkeys or packet pairs
+    Block,
+}
+
+impl Unit {
+    pub fn split(&self, input: &str) -> Vec<String> {
+        match self {
+            Unit::Line => input.lines().map(String::from).collect(),
+            Unit::Block => input.trim_end().split("\n\n").map(String::from).collect(),
+        }
+    }
+
+    pub fn join(&self, units: &[String]) -> String {
+        let separator = match self {
+            Unit::Line => "\n",
+            Unit::Block => "\n\n",
+        };
+
+        let mut result = units.join(separator);
+        result.push('\n');
+        result
+    }
+}
+
+// removes chunks of units for as long as `fails` keeps returning `true`
+//
+// starts with halves of the input and halves the chunk size whenever nothing can be removed,
+// the result is minimal in the sense that removing any single unit makes the failure go away
+pub fn minimize(mut units: Vec<String>, mut fails: impl FnMut(&[String]) -> bool) -> Vec<String> {
+    let mut chunk = (units.len() / 2).max(1);
+
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = units[..start]
+                .iter()
+                .chain(&units[end..])
+                .cloned()
+                .collect::<Vec<String>>();
+
+            if !candidate.is_empty() && fails(&candidate) {
+                units = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if !removed {
+            if chunk == 1 {
+                return units;
+            }
+            chunk /= 2;
+        }
+        chunk = chunk.min(units.len()).max(1);
+    }
+}
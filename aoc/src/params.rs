@@ -0,0 +1,279 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+// puzzle parameters collected from a config file and command line flags
+//
+// the config file is a small TOML-ish subset - one `key = value` pair per line,
+// `#` starts a comment and string values may be quoted
+//
+// flags are passed as `--key value` or `--key=value`, switches taken with `flag` are
+// just `--key` and never swallow the argument after them - so a bare `--key` only
+// decides whether it has a value once it is taken
+// `--config path` loads a config file, flags always take precedence over the file
+//
+// `-` and `_` are interchangeable in keys, so `--disk-size` sets `disk_size`
+
+#[derive(Debug, Default, Clone)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+    // bare `--key` flags with the index of the argument right after them, if it can be a value
+    bare: BTreeMap<String, Option<usize>>,
+    // command line arguments that are not flags, `None` once a flag took one as its value
+    args: Vec<Option<String>>,
+}
+
+#[derive(Debug)]
+pub enum ParamError {
+    Syntax { line: usize, content: String },
+    Invalid { key: String, value: String },
+    Missing(String),
+    Unknown(Vec<String>),
+    Config { path: String, message: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Syntax { line, content } => {
+                write!(
+                    f,
+                    "expected `key = value` on config line {line}, got `{content}`"
+                )
+            }
+            ParamError::Invalid { key, value } => {
+                write!(f, "invalid value `{value}` for parameter `{key}`")
+            }
+            ParamError::Missing(key) => write!(f, "missing value for parameter `{key}`"),
+            ParamError::Unknown(keys) => write!(f, "unknown parameters: {}", keys.join(", ")),
+            ParamError::Config { path, message } => {
+                write!(f, "could not read config file {path}: {message}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParamError {}
+
+fn normalize_key(key: &str) -> String {
+    key.trim().replace('-', "_")
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    match (value.strip_prefix('"'), value.strip_prefix('\'')) {
+        (Some(rest), _) => rest.strip_suffix('"').unwrap_or(value),
+        (_, Some(rest)) => rest.strip_suffix('\'').unwrap_or(value),
+        _ => value,
+    }
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ParamError> {
+        let mut params = Self::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = match line.find('#') {
+                Some(comment) => &line[..comment],
+                None => line,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => params.set(key, unquote(value)),
+                _ => {
+                    return Err(ParamError::Syntax {
+                        line: i + 1,
+                        content: line.trim().to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(params)
+    }
+
+    // collects the flags, the remaining positional arguments are returned by `finish`
+    #[cfg(feature = "std")]
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, ParamError> {
+        let mut flags = Self::new();
+        let mut config_path: Option<String> = None;
+
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            let flag = match arg.strip_prefix("--") {
+                Some(flag) if !flag.is_empty() => flag,
+                _ => {
+                    flags.args.push(Some(arg));
+                    continue;
+                }
+            };
+
+            match flag.split_once('=') {
+                Some((key, value)) if normalize_key(key) == "config" => {
+                    config_path = Some(value.to_string())
+                }
+                Some((key, value)) => flags.set(key, value),
+                None if normalize_key(flag) == "config" => {
+                    match args.next_if(|next| !next.starts_with("--")) {
+                        Some(path) => config_path = Some(path),
+                        None => return Err(ParamError::Missing("config".to_string())),
+                    }
+                }
+                None => {
+                    let next = match args.peek() {
+                        Some(next) if !next.starts_with("--") => Some(flags.args.len()),
+                        _ => None,
+                    };
+                    flags.bare.insert(normalize_key(flag), next);
+                }
+            }
+        }
+
+        let mut params = match config_path {
+            Some(path) => {
+                let content = std::fs::read_to_string(&path).map_err(|e| ParamError::Config {
+                    path: path.clone(),
+                    message: e.to_string(),
+                })?;
+                Self::parse(&content).map_err(|e| ParamError::Config {
+                    path,
+                    message: e.to_string(),
+                })?
+            }
+            None => Self::new(),
+        };
+        params.values.extend(flags.values);
+        params.bare = flags.bare;
+        params.args = flags.args;
+
+        Ok(params)
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.values.insert(normalize_key(key), value.to_string());
+    }
+
+    // removes the parameter, falling back to `default` when it was not set
+    //
+    // a bare `--key` takes the argument after it as its value
+    pub fn take<T: FromStr>(&mut self, key: &str, default: T) -> Result<T, ParamError> {
+        let value = match self.bare.remove(key) {
+            Some(next) => {
+                self.values.remove(key);
+                next.and_then(|i| self.args[i].take())
+                    .ok_or_else(|| ParamError::Missing(key.to_string()))?
+            }
+            None => match self.values.remove(key) {
+                Some(value) => value,
+                None => return Ok(default),
+            },
+        };
+
+        value.parse::<T>().map_err(|_| ParamError::Invalid {
+            key: key.to_string(),
+            value,
+        })
+    }
+
+    // removes a switch, a bare `--key` is `true` and leaves the argument after it alone
+    pub fn flag(&mut self, key: &str) -> Result<bool, ParamError> {
+        if self.bare.remove(key).is_some() {
+            self.values.remove(key);
+            return Ok(true);
+        }
+
+        self.take(key, false)
+    }
+
+    // fails if any parameter was not taken - catches typos in flags and config files
+    //
+    // returns the positional arguments, without the values taken by flags
+    pub fn finish(self) -> Result<Vec<String>, ParamError> {
+        if self.values.is_empty() && self.bare.is_empty() {
+            return Ok(self.args.into_iter().flatten().collect());
+        }
+
+        let mut keys = self.values.into_keys().collect::<Vec<String>>();
+        keys.extend(self.bare.into_keys());
+        keys.sort();
+        keys.dedup();
+        Err(ParamError::Unknown(keys))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn from_args(args: &[&str]) -> Params {
+        Params::from_args(args.iter().map(|arg| arg.to_string())).unwrap()
+    }
+
+    #[test]
+    fn switch_before_positional() {
+        let mut params = from_args(&["day05", "--step", "sample"]);
+        assert!(params.flag("step").unwrap());
+        assert_eq!(params.finish().unwrap(), ["day05", "sample"]);
+    }
+
+    #[test]
+    fn switches_around_positionals() {
+        let mut params = from_args(&["day06", "--stream", "-", "--every", "4"]);
+        assert!(params.flag("stream").unwrap());
+        assert!(params.flag("every").unwrap());
+        assert!(!params.flag("all").unwrap());
+        assert_eq!(params.finish().unwrap(), ["day06", "-", "4"]);
+    }
+
+    #[test]
+    fn value_before_positional() {
+        let mut params = from_args(&["day05", "--crane", "9001", "sample", "--step"]);
+        assert_eq!(params.take("crane", String::new()).unwrap(), "9001");
+        assert!(params.flag("step").unwrap());
+        assert_eq!(params.finish().unwrap(), ["day05", "sample"]);
+    }
+
+    #[test]
+    fn value_after_equals() {
+        let mut params = from_args(&["day01", "--top=5", "sample", "--step=false"]);
+        assert_eq!(params.take("top", 3).unwrap(), 5);
+        assert!(!params.flag("step").unwrap());
+        assert_eq!(params.finish().unwrap(), ["day01", "sample"]);
+    }
+
+    #[test]
+    fn missing_value() {
+        let mut params = from_args(&["day01", "sample", "--top"]);
+        assert!(matches!(params.take("top", 3), Err(ParamError::Missing(_))));
+
+        let mut params = from_args(&["day01", "--top", "--step", "sample"]);
+        assert!(matches!(params.take("top", 3), Err(ParamError::Missing(_))));
+    }
+
+    #[test]
+    fn dashes_in_keys() {
+        let mut params = from_args(&["day07", "--disk-size", "100", "sample"]);
+        assert_eq!(params.take("disk_size", 0).unwrap(), 100);
+        assert_eq!(params.finish().unwrap(), ["day07", "sample"]);
+    }
+
+    #[test]
+    fn unknown_flags() {
+        let mut params = from_args(&["day05", "--stpe", "sample", "--crane=9001"]);
+        assert!(!params.flag("step").unwrap());
+        match params.finish() {
+            Err(ParamError::Unknown(keys)) => assert_eq!(keys, ["crane", "stpe"]),
+            other => panic!("expected unknown parameters, got {other:?}"),
+        }
+    }
+}
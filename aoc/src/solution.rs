@@ -0,0 +1,92 @@
+use alloc::string::String;
+use core::fmt;
+
+// every day exposes `solve(input: &str, part: Part) -> Result<Answer, Error>`
+// the solvers do no I/O and no printing, the binaries take care of that
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            // multiline answers (e.g. rendered letters) start on their own line
+            Answer::Text(text) if text.contains('\n') => write!(f, "\n{text}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(number: $t) -> Self {
+                    Answer::Number(number as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u8, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    // `line` is 1-based, 0 when the error is not tied to a single line
+    Parse { line: usize, message: String },
+    Unsolvable(String),
+    Unsupported(Part),
+}
+
+impl Error {
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse { line: 0, message } => write!(f, "invalid input: {message}"),
+            Error::Parse { line, message } => write!(f, "invalid input on line {line}: {message}"),
+            Error::Unsolvable(message) => write!(f, "no solution: {message}"),
+            Error::Unsupported(_) => write!(f, "not implemented"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
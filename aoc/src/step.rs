@@ -0,0 +1,224 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+// a simulation that can be advanced one step at a time
+pub trait Simulation {
+    // advances the simulation, returns `false` once there is nothing left to do
+    fn step(&mut self) -> bool;
+
+    // the current state, usually the day's `pretty_print`
+    fn render(&self) -> String;
+
+    // named values shown after each step and usable in conditions
+    fn inspect(&self) -> Vec<(&'static str, String)>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+// a condition such as `rock_counter == 1748` or `position == (3, 4)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    name: String,
+    comparison: Comparison,
+    value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConditionError {
+    Syntax(String),
+    UnknownValue(String),
+}
+
+impl fmt::Display for ConditionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConditionError::Syntax(condition) => {
+                write!(f, "expected `name <op> value`, got `{condition}`")
+            }
+            ConditionError::UnknownValue(name) => write!(f, "unknown value `{name}`"),
+        }
+    }
+}
+
+// whitespace is ignored, so `(3,4)` matches `(3, 4)`
+fn normalize(value: &str) -> String {
+    value.chars().filter(|e| !e.is_whitespace()).collect()
+}
+
+impl Condition {
+    pub fn parse(condition: &str) -> Result<Self, ConditionError> {
+        // two character operators first, so `>=` is not read as `>`
+        let operators = [
+            ("==", Comparison::Equal),
+            ("!=", Comparison::NotEqual),
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+        ];
+
+        for (operator, comparison) in operators {
+            if let Some((name, value)) = condition.split_once(operator) {
+                let (name, value) = (name.trim(), value.trim());
+                if name.is_empty() || value.is_empty() {
+                    break;
+                }
+
+                return Ok(Self {
+                    name: name.to_string(),
+                    comparison,
+                    value: normalize(value),
+                });
+            }
+        }
+
+        Err(ConditionError::Syntax(condition.to_string()))
+    }
+
+    pub fn check(&self, values: &[(&'static str, String)]) -> Result<bool, ConditionError> {
+        let (_, actual) = values
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .ok_or_else(|| ConditionError::UnknownValue(self.name.clone()))?;
+        let actual = normalize(actual);
+
+        // numbers are compared as numbers, everything else as text
+        let ordering = match (actual.parse::<i64>(), self.value.parse::<i64>()) {
+            (Ok(actual), Ok(expected)) => actual.cmp(&expected),
+            _ => actual.as_str().cmp(self.value.as_str()),
+        };
+
+        Ok(match self.comparison {
+            Comparison::Equal => ordering.is_eq(),
+            Comparison::NotEqual => ordering.is_ne(),
+            Comparison::Less => ordering.is_lt(),
+            Comparison::LessOrEqual => ordering.is_le(),
+            Comparison::Greater => ordering.is_gt(),
+            Comparison::GreaterOrEqual => ordering.is_ge(),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+const HELP: &str = "commands:
+  s [N], step [N]        advance one or N steps (an empty line steps once)
+  u <cond>, until <cond> run until the condition holds, e.g. `until rock_counter == 1748`
+  p, print               render the current state
+  i, inspect             show the inspectable values
+  h, help                show this help
+  q, quit                leave the step mode";
+
+#[cfg(feature = "std")]
+fn write_values(
+    output: &mut impl std::io::Write,
+    values: &[(&'static str, String)],
+) -> std::io::Result<()> {
+    let values = values
+        .iter()
+        .map(|(name, value)| alloc::format!("{name}={value}"))
+        .collect::<Vec<String>>();
+    writeln!(output, "{}", values.join(" "))
+}
+
+// interactive step mode - reads commands from `input` until it ends or `quit` is entered
+#[cfg(feature = "std")]
+pub fn run(
+    simulation: &mut impl Simulation,
+    input: impl std::io::BufRead,
+    mut output: impl std::io::Write,
+) -> std::io::Result<()> {
+    let mut finished = false;
+    let mut steps: u64 = 0;
+
+    writeln!(output, "{HELP}")?;
+    write_values(&mut output, &simulation.inspect())?;
+
+    for line in input.lines() {
+        let line = line?;
+        let (command, argument) = match line.trim().split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, argument.trim()),
+            None => (line.trim(), ""),
+        };
+
+        match command {
+            "" | "s" | "step" => {
+                let count = if argument.is_empty() {
+                    Ok(1)
+                } else {
+                    argument.parse::<u64>()
+                };
+                let Ok(count) = count else {
+                    writeln!(output, "invalid step count `{argument}`")?;
+                    continue;
+                };
+
+                for _ in 0..count {
+                    if finished {
+                        break;
+                    }
+                    finished = !simulation.step();
+                    if !finished {
+                        steps += 1;
+                    }
+                }
+            }
+            "u" | "until" => {
+                let condition = match Condition::parse(argument) {
+                    Ok(condition) => condition,
+                    Err(e) => {
+                        writeln!(output, "{e}")?;
+                        continue;
+                    }
+                };
+
+                loop {
+                    match condition.check(&simulation.inspect()) {
+                        Ok(true) => break,
+                        Ok(false) if finished => break,
+                        Ok(false) => {
+                            finished = !simulation.step();
+                            if !finished {
+                                steps += 1;
+                            }
+                        }
+                        Err(e) => {
+                            writeln!(output, "{e}")?;
+                            break;
+                        }
+                    }
+                }
+            }
+            "p" | "print" => {
+                writeln!(output, "{}", simulation.render())?;
+                continue;
+            }
+            "i" | "inspect" => {}
+            "h" | "help" => {
+                writeln!(output, "{HELP}")?;
+                continue;
+            }
+            "q" | "quit" => break,
+            _ => {
+                writeln!(output, "unknown command `{command}`, try `help`")?;
+                continue;
+            }
+        }
+
+        write!(output, "step {steps}: ")?;
+        write_values(&mut output, &simulation.inspect())?;
+        if finished {
+            writeln!(output, "simulation finished")?;
+        }
+    }
+
+    Ok(())
+}
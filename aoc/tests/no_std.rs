@@ -0,0 +1,18 @@
+use std::process::Command;
+
+// the solution types and config parsing must keep building without `std`,
+// otherwise the solutions can no longer be embedded in wasm hosts
+#[test]
+fn builds_without_std() {
+    let status = Command::new(env!("CARGO"))
+        .args(["check", "--quiet", "--lib", "--no-default-features"])
+        .args([
+            "--manifest-path",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+        ])
+        .args(["--target-dir", env!("CARGO_TARGET_TMPDIR")])
+        .status()
+        .expect("Could not run cargo!");
+
+    assert!(status.success());
+}
@@ -0,0 +1,185 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+mod report;
+pub use report::Report;
+
+pub struct Config {
+    // how many elves are summed up in part 2
+    pub top: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { top: 3 }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        Ok(Self {
+            top: params.take("top", default.top)?,
+        })
+    }
+}
+
+// an elf and the calories it carries, elves are numbered from 1 in input order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+// an elf's pack, as read from the input
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Pack {
+    pub calories: u64,
+    pub items: usize,
+}
+
+// groups the input lines into packs
+// a blank line ends the current pack, two blank lines in a row mean an elf with an empty pack
+#[derive(Default)]
+struct Grouping {
+    pack: Option<Pack>,
+    line: usize,
+}
+
+impl Grouping {
+    // returns the finished pack when the line ends one
+    fn push_line(&mut self, line: &str) -> Result<Option<Pack>, Error> {
+        self.line += 1;
+
+        if line.is_empty() {
+            return Ok(Some(self.pack.take().unwrap_or_default()));
+        }
+
+        let calories = line
+            .parse::<u64>()
+            .map_err(|_| Error::parse(self.line, format!("expected calories, got {line}")))?;
+
+        let pack = self.pack.get_or_insert_with(Pack::default);
+        pack.calories = pack
+            .calories
+            .checked_add(calories)
+            .ok_or_else(|| Error::parse(self.line, "too many calories"))?;
+        pack.items += 1;
+
+        Ok(None)
+    }
+
+    // the last pack is not followed by a blank line
+    fn finish(self) -> Option<Pack> {
+        self.pack
+    }
+}
+
+// keeps the `k` elves with the most calories while reading the input line by line
+// only `k` elves are held in memory, so the input can be streamed
+pub struct TopElves {
+    k: usize,
+    // min-heap - the weakest of the top elves is on top and gets replaced first
+    // on a tie, the elf that came first stays
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+    elves: usize,
+    grouping: Grouping,
+}
+
+impl TopElves {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+            elves: 0,
+            grouping: Grouping::default(),
+        }
+    }
+
+    pub fn push_line(&mut self, line: &str) -> Result<(), Error> {
+        if let Some(pack) = self.grouping.push_line(line)? {
+            self.push_pack(pack);
+        }
+        Ok(())
+    }
+
+    fn push_pack(&mut self, pack: Pack) {
+        self.elves += 1;
+
+        self.heap
+            .push(Reverse((pack.calories, Reverse(self.elves))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    // the top elves, most calories first
+    pub fn finish(mut self) -> Vec<Elf> {
+        if let Some(pack) = std::mem::take(&mut self.grouping).finish() {
+            self.push_pack(pack);
+        }
+
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect()
+    }
+}
+
+// every elf's pack, in input order
+#[derive(Default)]
+pub struct Inventory {
+    packs: Vec<Pack>,
+    grouping: Grouping,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_line(&mut self, line: &str) -> Result<(), Error> {
+        if let Some(pack) = self.grouping.push_line(line)? {
+            self.packs.push(pack);
+        }
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Vec<Pack> {
+        if let Some(pack) = self.grouping.finish() {
+            self.packs.push(pack);
+        }
+        self.packs
+    }
+}
+
+pub fn top_elves(input: &str, k: usize) -> Result<Vec<Elf>, Error> {
+    let mut top = TopElves::new(k);
+    for line in input.lines() {
+        top.push_line(line)?;
+    }
+
+    Ok(top.finish())
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    match part {
+        Part::One => top_elves(input, 1)?
+            .first()
+            .map(|e| e.calories.into())
+            .ok_or_else(|| Error::unsolvable("no elves found")),
+        Part::Two => Ok(top_elves(input, config.top)?
+            .iter()
+            .map(|e| e.calories)
+            .sum::<u64>()
+            .into()),
+    }
+}
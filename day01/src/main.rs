@@ -1,38 +1,92 @@
+use aoc::params::Params;
+use aoc::{Error, Part};
+use day01::{Config, Elf, Inventory, Report, TopElves};
 use std::env;
-use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::process;
 
-// #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-// struct Elf {
-//     calories: i32,
-// }
+// reads the file line by line, the input is never held in memory as a whole
+fn read_top_elves(file_path: &str, k: usize) -> Result<Vec<Elf>, Error> {
+    let file = File::open(file_path).expect("Should have been able to read the file");
+    let mut top = TopElves::new(k);
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+    for line in BufReader::new(file).lines() {
+        top.push_line(&line.expect("Should have been able to read the file"))?;
+    }
 
-    let file_path = &args[1];
+    Ok(top.finish())
+}
 
-    println!("In file {}", file_path);
+fn read_report(file_path: &str) -> Result<Report, Error> {
+    let file = File::open(file_path).expect("Should have been able to read the file");
+    let mut inventory = Inventory::new();
 
-    let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    let lines = contents.lines();
+    for line in BufReader::new(file).lines() {
+        inventory.push_line(&line.expect("Should have been able to read the file"))?;
+    }
+
+    Report::new(&inventory.finish())
+}
+
+fn main() {
+    let mut params = Params::from_args(env::args()).unwrap_or_else(|e| panic!("{e}"));
+    let config = Config::from_params(&mut params).unwrap_or_else(|e| panic!("{e}"));
+    // `text` or `json` prints the inventory statistics instead of the answers
+    let report: String = params
+        .take("report", String::new())
+        .unwrap_or_else(|e| panic!("{e}"));
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
 
-    let mut elves: Vec<i32> = Vec::new();
-    let mut sum = 0;
+    let file_path = args
+        .get(1)
+        .expect("Pass in input file path as the first argument!");
 
-    for line in lines {
-        if line == "" {
-            elves.push(sum);
-            sum = 0;
-        } else {
-            sum += line.parse::<i32>().unwrap();
+    if !report.is_empty() {
+        if report != "text" && report != "json" {
+            panic!("Unknown report format {report}, expected `text` or `json`!");
         }
+
+        match read_report(file_path) {
+            Ok(statistics) if report == "json" => println!("{}", statistics.to_json()),
+            Ok(statistics) => print!("{}", statistics.to_text()),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        return;
     }
 
-    elves.sort();
+    println!("In file {}", file_path);
+
+    // part 1 only needs the best elf, so a single pass covers both parts
+    let elves = match read_top_elves(file_path, config.top.max(1)) {
+        Ok(elves) => elves,
+        Err(e) => {
+            for part in [Part::One, Part::Two] {
+                eprintln!("{part}: {e}");
+            }
+            process::exit(1);
+        }
+    };
 
-    println!("most calories: {}", elves.last().unwrap());
+    match elves.first() {
+        Some(elf) => println!("{}: {}", Part::One, elf.calories),
+        None => eprintln!("{}: {}", Part::One, Error::unsolvable("no elves found")),
+    }
 
-    let three_largest_sum: i32 = elves.iter().rev().take(3).sum();
+    let top = &elves[..config.top.min(elves.len())];
+    println!(
+        "{}: {}",
+        Part::Two,
+        top.iter().map(|e| e.calories).sum::<u64>()
+    );
 
-    println!("sum of three largest: {}", three_largest_sum);
+    for elf in top {
+        println!("elf #{}: {}", elf.index, elf.calories);
+    }
+    if elves.is_empty() {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,192 @@
+use crate::Pack;
+use aoc::Error;
+use std::fmt::Write;
+
+const PERCENTILES: [usize; 5] = [10, 25, 50, 75, 90];
+const HISTOGRAM_BINS: u64 = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+// summary statistics of an inventory
+pub struct Report {
+    pub elves: usize,
+    pub items: usize,
+    pub min_items: usize,
+    pub max_items: usize,
+    pub total_calories: u64,
+    pub min_calories: u64,
+    pub max_calories: u64,
+    pub mean_calories: f64,
+    pub median_calories: f64,
+    // (percentile, calories) using the nearest rank
+    pub percentiles: Vec<(usize, u64)>,
+    // (from, to, elves) - both bounds are inclusive
+    pub histogram: Vec<(u64, u64, usize)>,
+    // elves are numbered from 1
+    pub empty_packs: Vec<usize>,
+}
+
+impl Report {
+    pub fn new(packs: &[Pack]) -> Result<Self, Error> {
+        if packs.is_empty() {
+            return Err(Error::unsolvable("no elves found"));
+        }
+
+        let mut totals = packs.iter().map(|e| e.calories).collect::<Vec<u64>>();
+        totals.sort_unstable();
+
+        let elves = packs.len();
+        let total_calories = totals.iter().sum::<u64>();
+        let (min_calories, max_calories) = (totals[0], totals[elves - 1]);
+
+        let median_calories = if elves.is_multiple_of(2) {
+            (totals[elves / 2 - 1] as f64 + totals[elves / 2] as f64) / 2.0
+        } else {
+            totals[elves / 2] as f64
+        };
+
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p * elves).div_ceil(100).max(1);
+                (p, totals[rank - 1])
+            })
+            .collect();
+
+        let range = max_calories - min_calories + 1;
+        let bin_width = range.div_ceil(HISTOGRAM_BINS.min(elves as u64));
+        // rounding the width up can leave fewer bins than asked for, none may start past the max
+        let bins = range.div_ceil(bin_width);
+        let mut histogram = (0..bins)
+            .map(|i| {
+                let from = min_calories + i * bin_width;
+                (from, (from + bin_width - 1).min(max_calories), 0)
+            })
+            .collect::<Vec<(u64, u64, usize)>>();
+        for total in &totals {
+            histogram[((total - min_calories) / bin_width) as usize].2 += 1;
+        }
+
+        Ok(Self {
+            elves,
+            items: packs.iter().map(|e| e.items).sum(),
+            min_items: packs.iter().map(|e| e.items).min().unwrap_or(0),
+            max_items: packs.iter().map(|e| e.items).max().unwrap_or(0),
+            total_calories,
+            min_calories,
+            max_calories,
+            mean_calories: total_calories as f64 / elves as f64,
+            median_calories,
+            percentiles,
+            histogram,
+            empty_packs: packs
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| e.items == 0)
+                .map(|(i, _)| i + 1)
+                .collect(),
+        })
+    }
+
+    pub fn mean_items(&self) -> f64 {
+        self.items as f64 / self.elves as f64
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut result = String::new();
+
+        writeln!(result, "elves: {}", self.elves).unwrap();
+        writeln!(
+            result,
+            "items: {} (per elf: min {}, mean {:.2}, max {})",
+            self.items,
+            self.min_items,
+            self.mean_items(),
+            self.max_items
+        )
+        .unwrap();
+        writeln!(
+            result,
+            "calories: {} (per elf: min {}, mean {:.2}, median {:.1}, max {})",
+            self.total_calories,
+            self.min_calories,
+            self.mean_calories,
+            self.median_calories,
+            self.max_calories
+        )
+        .unwrap();
+
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(p, calories)| format!("p{p} {calories}"))
+            .collect::<Vec<String>>();
+        writeln!(result, "percentiles: {}", percentiles.join(", ")).unwrap();
+
+        let empty_packs = if self.empty_packs.is_empty() {
+            "none".to_string()
+        } else {
+            self.empty_packs
+                .iter()
+                .map(|e| format!("#{e}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        writeln!(result, "empty packs: {empty_packs}").unwrap();
+
+        writeln!(result, "histogram:").unwrap();
+        let largest_bin = self.histogram.iter().map(|e| e.2).max().unwrap_or(1);
+        let label_width = self.max_calories.to_string().len();
+        for (from, to, elves) in &self.histogram {
+            let bar = "#".repeat((elves * HISTOGRAM_WIDTH).div_ceil(largest_bin));
+            writeln!(
+                result,
+                "  {from:>label_width$} - {to:>label_width$} | {bar} {elves}"
+            )
+            .unwrap();
+        }
+
+        result
+    }
+
+    pub fn to_json(&self) -> String {
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(p, calories)| format!("\"p{p}\":{calories}"))
+            .collect::<Vec<String>>();
+        let histogram = self
+            .histogram
+            .iter()
+            .map(|(from, to, elves)| format!("{{\"from\":{from},\"to\":{to},\"elves\":{elves}}}"))
+            .collect::<Vec<String>>();
+        let empty_packs = self
+            .empty_packs
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>();
+
+        format!(
+            concat!(
+                "{{\"elves\":{},",
+                "\"items\":{{\"total\":{},\"min\":{},\"mean\":{},\"max\":{}}},",
+                "\"calories\":{{\"total\":{},\"min\":{},\"mean\":{},\"median\":{},\"max\":{},",
+                "\"percentiles\":{{{}}}}},",
+                "\"histogram\":[{}],",
+                "\"empty_packs\":[{}]}}"
+            ),
+            self.elves,
+            self.items,
+            self.min_items,
+            self.mean_items(),
+            self.max_items,
+            self.total_calories,
+            self.min_calories,
+            self.mean_calories,
+            self.median_calories,
+            self.max_calories,
+            percentiles.join(","),
+            histogram.join(","),
+            empty_packs.join(","),
+        )
+    }
+}
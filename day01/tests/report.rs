@@ -0,0 +1,55 @@
+use day01::{Pack, Report};
+
+fn packs(calories: &[u64]) -> Vec<Pack> {
+    calories
+        .iter()
+        .map(|&calories| Pack { calories, items: 1 })
+        .collect()
+}
+
+fn assert_bins_cover(report: &Report) {
+    for &(from, to, _) in &report.histogram {
+        assert!(from <= to, "bin {from} - {to}");
+        assert!(to <= report.max_calories, "bin {from} - {to}");
+    }
+    assert_eq!(report.histogram[0].0, report.min_calories);
+    assert_eq!(report.histogram.last().unwrap().1, report.max_calories);
+    assert_eq!(
+        report.histogram.iter().map(|e| e.2).sum::<usize>(),
+        report.elves
+    );
+}
+
+#[test]
+fn uneven_range() {
+    // 11 calorie values in 10 bins round up to a width of 2, so only 6 bins are needed
+    let report = Report::new(&packs(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 10])).unwrap();
+
+    assert_eq!(
+        report.histogram,
+        vec![
+            (0, 1, 2),
+            (2, 3, 2),
+            (4, 5, 2),
+            (6, 7, 2),
+            (8, 9, 1),
+            (10, 10, 1)
+        ]
+    );
+    assert_bins_cover(&report);
+}
+
+#[test]
+fn even_range() {
+    let report = Report::new(&packs(&[1000, 2000, 3000, 4000, 5000])).unwrap();
+
+    assert_eq!(report.histogram.len(), 5);
+    assert_bins_cover(&report);
+}
+
+#[test]
+fn single_elf() {
+    let report = Report::new(&packs(&[24000])).unwrap();
+
+    assert_eq!(report.histogram, vec![(24000, 24000, 1)]);
+}
@@ -0,0 +1,340 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+use std::cmp::Reverse;
+
+mod tournament;
+pub use tournament::{Opponent, Strategy, Summary, Tournament};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+// the rules of a cyclic hand game, rock-paper-scissors by default
+//
+// the shapes are listed in cycle order and every shape beats the (n - 1) / 2 shapes before it,
+// which needs an odd number of at least three shapes - e.g. `rock spock paper lizard scissors`
+pub struct Rules {
+    pub shapes: Vec<String>,
+    // symbols of the shapes in the first and second column of the guide
+    pub opponent: Vec<String>,
+    pub player: Vec<String>,
+    pub shape_scores: Vec<u32>,
+    // symbols of a loss, a draw and a win in the second column for part 2
+    pub outcomes: Vec<String>,
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+fn words(value: &str) -> Vec<String> {
+    value.split_whitespace().map(String::from).collect()
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            shapes: words("rock paper scissors"),
+            opponent: words("A B C"),
+            player: words("X Y Z"),
+            shape_scores: vec![1, 2, 3],
+            outcomes: words("X Y Z"),
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+impl Rules {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        let mut take_list = |key: &str, default: &[String]| {
+            params
+                .take(key, default.join(" "))
+                .map(|value: String| words(&value))
+        };
+        let shapes = take_list("shapes", &default.shapes)?;
+        let opponent = take_list("opponent", &default.opponent)?;
+        let player = take_list("player", &default.player)?;
+        let outcomes = take_list("outcomes", &default.outcomes)?;
+
+        let scores = params.take("shape_scores", String::new())?;
+        let shape_scores = if scores.is_empty() {
+            // scored by position unless given
+            (1..=shapes.len() as u32).collect()
+        } else {
+            scores
+                .split_whitespace()
+                .map(|e| e.parse::<u32>())
+                .collect::<Result<Vec<u32>, _>>()
+                .map_err(|_| ParamError::Invalid {
+                    key: "shape_scores".to_string(),
+                    value: scores.clone(),
+                })?
+        };
+
+        let rules = Self {
+            shapes,
+            opponent,
+            player,
+            shape_scores,
+            outcomes,
+            loss: params.take("loss", default.loss)?,
+            draw: params.take("draw", default.draw)?,
+            win: params.take("win", default.win)?,
+        };
+
+        let invalid = |key: &str, value: &[String]| ParamError::Invalid {
+            key: key.to_string(),
+            value: value.join(" "),
+        };
+        if rules.shapes.len() < 3 || rules.shapes.len().is_multiple_of(2) {
+            return Err(invalid("shapes", &rules.shapes));
+        }
+        for (key, list) in [("opponent", &rules.opponent), ("player", &rules.player)] {
+            if list.len() != rules.shapes.len() {
+                return Err(invalid(key, list));
+            }
+        }
+        if rules.shape_scores.len() != rules.shapes.len() {
+            let scores = rules.shape_scores.iter().map(|e| e.to_string());
+            return Err(invalid("shape_scores", &scores.collect::<Vec<String>>()));
+        }
+        if rules.outcomes.len() != 3 {
+            return Err(invalid("outcomes", &rules.outcomes));
+        }
+
+        Ok(rules)
+    }
+
+    pub fn outcome(&self, player: usize, opponent: usize) -> Outcome {
+        let n = self.shapes.len();
+        match (player + n - opponent) % n {
+            0 => Outcome::Draw,
+            distance if distance <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+
+    pub fn score(&self, player: usize, opponent: usize) -> u32 {
+        self.shape_scores[player] + self.outcome_score(self.outcome(player, opponent))
+    }
+
+    // with more than three shapes several shapes lead to the outcome, the best scoring one is played
+    pub fn shape_for(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|&player| self.outcome(player, opponent) == outcome)
+            .max_by_key(|&player| (self.shape_scores[player], usize::MAX - player))
+            .expect("Every outcome is possible with an odd number of shapes!")
+    }
+}
+
+// a line of the strategy guide - the opponent's shape and the still encrypted second column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub response: String,
+}
+
+pub fn parse_guide(input: &str, rules: &Rules) -> Result<Vec<Round>, Error> {
+    let mut rounds = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let (opponent, response) = match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [opponent, response] => (opponent, response),
+            _ => return Err(Error::parse(i + 1, format!("unexpected round {line}"))),
+        };
+
+        let opponent = rules
+            .opponent
+            .iter()
+            .position(|e| e == opponent)
+            .ok_or_else(|| Error::parse(i + 1, format!("unexpected round {line}")))?;
+
+        rounds.push(Round {
+            opponent,
+            response: response.to_string(),
+        });
+    }
+
+    Ok(rounds)
+}
+
+// lexicographic permutations of 0..n
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+
+    let mut result = vec![];
+    for first in 0..n {
+        for rest in permutations(n - 1) {
+            let mut permutation = vec![first];
+            permutation.extend(rest.into_iter().map(|e| if e >= first { e + 1 } else { e }));
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    // the second column is the shape to play, like in part 1
+    Shapes,
+    // the second column is the outcome to reach, like in part 2
+    Outcomes,
+}
+
+// one possible meaning of the second column and the score the guide gives with it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub reading: Reading,
+    // (symbol, meaning) pairs
+    pub mapping: Vec<(String, String)>,
+    pub score: u32,
+}
+
+impl Interpretation {
+    // true for the mapping the puzzle itself uses
+    pub fn is_puzzle_reading(&self, rules: &Rules) -> bool {
+        let meanings = match self.reading {
+            Reading::Shapes => rules.shapes.clone(),
+            Reading::Outcomes => vec!["loss".into(), "draw".into(), "win".into()],
+        };
+        self.mapping.iter().map(|(_, e)| e).eq(meanings.iter())
+    }
+}
+
+// scores the guide with every mapping of the second column to shapes and to outcomes,
+// best first
+pub fn interpretations(rounds: &[Round], rules: &Rules) -> Result<Vec<Interpretation>, Error> {
+    let column = |round: &Round, symbols: &[String], line: usize| {
+        symbols
+            .iter()
+            .position(|e| *e == round.response)
+            .ok_or_else(|| Error::parse(line, format!("unexpected response {}", round.response)))
+    };
+
+    let mut result = vec![];
+
+    let shape_columns = rounds
+        .iter()
+        .enumerate()
+        .map(|(i, round)| column(round, &rules.player, i + 1))
+        .collect::<Result<Vec<usize>, Error>>();
+    // a guide using outcome symbols only cannot be read as shapes, that is fine
+    if let Ok(columns) = &shape_columns {
+        for permutation in permutations(rules.shapes.len()) {
+            let score = rounds
+                .iter()
+                .zip(columns)
+                .map(|(round, &column)| rules.score(permutation[column], round.opponent))
+                .sum();
+            let mapping = rules
+                .player
+                .iter()
+                .zip(&permutation)
+                .map(|(symbol, &shape)| (symbol.clone(), rules.shapes[shape].clone()))
+                .collect();
+
+            result.push(Interpretation {
+                reading: Reading::Shapes,
+                mapping,
+                score,
+            });
+        }
+    }
+
+    let outcome_columns = rounds
+        .iter()
+        .enumerate()
+        .map(|(i, round)| column(round, &rules.outcomes, i + 1))
+        .collect::<Result<Vec<usize>, Error>>();
+    if let Ok(columns) = &outcome_columns {
+        let outcomes = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+        let names = ["loss", "draw", "win"];
+
+        for permutation in permutations(outcomes.len()) {
+            let score = rounds
+                .iter()
+                .zip(columns)
+                .map(|(round, &column)| {
+                    let outcome = outcomes[permutation[column]];
+                    rules.score(rules.shape_for(round.opponent, outcome), round.opponent)
+                })
+                .sum();
+            let mapping = rules
+                .outcomes
+                .iter()
+                .zip(&permutation)
+                .map(|(symbol, &outcome)| (symbol.clone(), names[outcome].to_string()))
+                .collect();
+
+            result.push(Interpretation {
+                reading: Reading::Outcomes,
+                mapping,
+                score,
+            });
+        }
+    }
+
+    if result.is_empty() {
+        // report the first line that fits neither reading
+        return shape_columns.and(outcome_columns).map(|_| vec![]);
+    }
+
+    // stable, so ties keep the shapes before the outcomes
+    result.sort_by_key(|e| Reverse(e.score));
+    Ok(result)
+}
+
+fn score_round(round: &Round, part: Part, rules: &Rules) -> Option<u32> {
+    match part {
+        Part::One => {
+            let player = rules.player.iter().position(|e| *e == round.response)?;
+            Some(rules.score(player, round.opponent))
+        }
+        Part::Two => {
+            let outcome = match rules.outcomes.iter().position(|e| *e == round.response)? {
+                0 => Outcome::Loss,
+                1 => Outcome::Draw,
+                _ => Outcome::Win,
+            };
+            let player = rules.shape_for(round.opponent, outcome);
+            Some(rules.score(player, round.opponent))
+        }
+    }
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Rules::default())
+}
+
+pub fn solve_with(input: &str, part: Part, rules: &Rules) -> Result<Answer, Error> {
+    let mut result = 0;
+    for (i, round) in parse_guide(input, rules)?.iter().enumerate() {
+        result += score_round(round, part, rules).ok_or_else(|| {
+            Error::parse(
+                i + 1,
+                format!(
+                    "unexpected round {} {}",
+                    rules.opponent[round.opponent], round.response
+                ),
+            )
+        })?;
+    }
+
+    Ok(result.into())
+}
@@ -1,8 +1,127 @@
+use aoc::params::Params;
+use aoc::Part;
+use day02::{Interpretation, Opponent, Reading, Rules, Strategy, Tournament};
 use std::env;
 use std::fs;
+use std::process;
+
+fn print_interpretations(interpretations: &[Interpretation], rules: &Rules) {
+    let (Some(best), Some(worst)) = (interpretations.first(), interpretations.last()) else {
+        return;
+    };
+
+    for interpretation in interpretations {
+        let reading = match interpretation.reading {
+            Reading::Shapes => "shapes",
+            Reading::Outcomes => "outcomes",
+        };
+        let mapping = interpretation
+            .mapping
+            .iter()
+            .map(|(symbol, meaning)| format!("{symbol}={meaning}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let mut notes = vec![];
+        if interpretation.score == best.score {
+            notes.push("best");
+        }
+        if interpretation.score == worst.score {
+            notes.push("worst");
+        }
+        if interpretation.is_puzzle_reading(rules) {
+            notes.push(match interpretation.reading {
+                Reading::Shapes => "part 1",
+                Reading::Outcomes => "part 2",
+            });
+        }
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!("  <- {}", notes.join(", "))
+        };
+
+        println!("{:>8} {reading:<8} {mapping}{notes}", interpretation.score);
+    }
+}
+
+// the simulation settings, an empty name means every opponent or strategy
+struct Simulation {
+    opponent: String,
+    strategy: String,
+    matches: usize,
+    rounds: usize,
+    seed: u64,
+}
+
+fn simulate(contents: &str, rules: &Rules, simulation: &Simulation) -> Result<(), String> {
+    let rounds = day02::parse_guide(contents, rules).map_err(|e| e.to_string())?;
+    let mut tournament = Tournament::new(rules, &rounds).map_err(|e| e.to_string())?;
+    tournament.matches = simulation.matches;
+    tournament.seed = simulation.seed;
+    if simulation.rounds > 0 {
+        tournament.rounds = simulation.rounds;
+    }
+
+    let opponents = match simulation.opponent.as_str() {
+        "" => Opponent::ALL.to_vec(),
+        name => vec![name.parse::<Opponent>()?],
+    };
+    let strategies = match simulation.strategy.as_str() {
+        "" => Strategy::ALL.to_vec(),
+        name => vec![name.parse::<Strategy>()?],
+    };
+
+    println!(
+        "{} matches of {} rounds, seed {}",
+        tournament.matches, tournament.rounds, tournament.seed
+    );
+    println!(
+        "{:<14} {:<8} {:>12} {:>10} {:>10} {:>10}",
+        "opponent", "strategy", "mean score", "wins", "draws", "losses"
+    );
+    for &opponent in &opponents {
+        for &strategy in &strategies {
+            let summary = tournament.simulate(opponent, strategy);
+            println!(
+                "{:<14} {:<8} {:>12.2} {:>10.2} {:>10.2} {:>10.2}",
+                opponent.name(),
+                strategy.name(),
+                summary.mean_score,
+                summary.wins,
+                summary.draws,
+                summary.losses
+            );
+        }
+    }
+
+    Ok(())
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    // `--config rules.toml` plays by other rules, see `rpsls.toml`
+    let mut params = Params::from_args(env::args()).unwrap_or_else(|e| panic!("{e}"));
+    let rules = Rules::from_params(&mut params).unwrap_or_else(|e| panic!("{e}"));
+    // scores every possible meaning of the second column instead of solving
+    let analyze = params.flag("analyze").unwrap_or_else(|e| panic!("{e}"));
+    // plays simulated matches against opponent models instead of solving
+    let simulate_matches = params.flag("simulate").unwrap_or_else(|e| panic!("{e}"));
+    let simulation = Simulation {
+        // `opponent` is taken by the rules already
+        opponent: params
+            .take("model", String::new())
+            .unwrap_or_else(|e| panic!("{e}")),
+        strategy: params
+            .take("strategy", String::new())
+            .unwrap_or_else(|e| panic!("{e}")),
+        matches: params
+            .take("matches", 1000)
+            .unwrap_or_else(|e| panic!("{e}")),
+        // 0 plays as many rounds as the guide has
+        rounds: params.take("rounds", 0).unwrap_or_else(|e| panic!("{e}")),
+        seed: params.take("seed", 1).unwrap_or_else(|e| panic!("{e}")),
+    };
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
 
     let file_path = args
         .get(1)
@@ -10,47 +129,35 @@ fn main() {
 
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
 
-    let lines = contents.lines();
-
-    let mut result = 0;
-    for line in lines {
-        let words = line.split_whitespace();
-
-        result += match words.collect::<Vec<&str>>().as_slice() {
-            ["A", "X"] => 3 + 1,
-            ["B", "X"] => 0 + 1,
-            ["C", "X"] => 6 + 1,
-            ["A", "Y"] => 6 + 2,
-            ["B", "Y"] => 3 + 2,
-            ["C", "Y"] => 0 + 2,
-            ["A", "Z"] => 0 + 3,
-            ["B", "Z"] => 6 + 3,
-            ["C", "Z"] => 3 + 3,
-            _ => unimplemented!(),
-        }
+    if simulate_matches {
+        simulate(&contents, &rules, &simulation).unwrap_or_else(|e| panic!("{e}"));
+        return;
     }
 
-    println!("result: {}", result);
-
-    let lines = contents.lines();
-
-    let mut result = 0;
-    for line in lines {
-        let words = line.split_whitespace();
-
-        result += match words.collect::<Vec<&str>>().as_slice() {
-            ["A", "X"] => 0 + 3,
-            ["B", "X"] => 0 + 1,
-            ["C", "X"] => 0 + 2,
-            ["A", "Y"] => 3 + 1,
-            ["B", "Y"] => 3 + 2,
-            ["C", "Y"] => 3 + 3,
-            ["A", "Z"] => 6 + 2,
-            ["B", "Z"] => 6 + 3,
-            ["C", "Z"] => 6 + 1,
-            _ => unimplemented!(),
+    if analyze {
+        let interpretations = day02::parse_guide(&contents, &rules)
+            .and_then(|rounds| day02::interpretations(&rounds, &rules));
+        match interpretations {
+            Ok(interpretations) => print_interpretations(&interpretations, &rules),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
         }
+        return;
     }
 
-    println!("result: {}", result);
+    let mut failed = false;
+    for part in [Part::One, Part::Two] {
+        match day02::solve_with(&contents, part, &rules) {
+            Ok(answer) => println!("{part}: {answer}"),
+            Err(e) => {
+                eprintln!("{part}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,287 @@
+use crate::{Outcome, Round, Rules};
+use aoc::Error;
+use std::str::FromStr;
+
+// splitmix64 - small, seedable and good enough for simulations
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // an index picked with probability proportional to its weight
+    fn weighted(&mut self, weights: &[usize]) -> usize {
+        let total = weights.iter().sum::<usize>();
+        if total == 0 {
+            return self.below(weights.len());
+        }
+
+        let mut pick = self.below(total);
+        for (i, &weight) in weights.iter().enumerate() {
+            if pick < weight {
+                return i;
+            }
+            pick -= weight;
+        }
+        unreachable!("The pick is always below the total weight.")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opponent {
+    // plays the first column of the guide, round after round
+    Guide,
+    Uniform,
+    // random, weighted by how often the guide plays each shape
+    Biased,
+    // plays the shape that won the previous round
+    RepeatLastWinner,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    // plays the second column of the guide read as shapes, like in part 1
+    Fixed,
+    // beats the shape the opponent played most often so far
+    CounterMostFrequent,
+    // predicts the opponent's next shape from what followed their last shape so far
+    Markov,
+}
+
+impl Opponent {
+    pub const ALL: [Opponent; 4] = [
+        Opponent::Guide,
+        Opponent::Uniform,
+        Opponent::Biased,
+        Opponent::RepeatLastWinner,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Opponent::Guide => "guide",
+            Opponent::Uniform => "uniform",
+            Opponent::Biased => "biased",
+            Opponent::RepeatLastWinner => "repeat-winner",
+        }
+    }
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 3] = [
+        Strategy::Fixed,
+        Strategy::CounterMostFrequent,
+        Strategy::Markov,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Fixed => "fixed",
+            Strategy::CounterMostFrequent => "counter",
+            Strategy::Markov => "markov",
+        }
+    }
+}
+
+impl FromStr for Opponent {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|e| e.name() == name)
+            .ok_or_else(|| format!("Unknown opponent {name}!"))
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|e| e.name() == name)
+            .ok_or_else(|| format!("Unknown strategy {name}!"))
+    }
+}
+
+// the average results of many matches between an opponent and a strategy
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub opponent: Opponent,
+    pub strategy: Strategy,
+    pub mean_score: f64,
+    pub wins: f64,
+    pub draws: f64,
+    pub losses: f64,
+}
+
+pub struct Tournament<'a> {
+    rules: &'a Rules,
+    // opponent and player shapes of the guide
+    guide: Vec<(usize, usize)>,
+    // the opponent's shape frequencies in the guide
+    frequencies: Vec<usize>,
+    pub rounds: usize,
+    pub matches: usize,
+    pub seed: u64,
+}
+
+impl<'a> Tournament<'a> {
+    // the player's column of the guide has to be readable as shapes
+    pub fn new(rules: &'a Rules, guide: &[Round]) -> Result<Self, Error> {
+        if guide.is_empty() {
+            return Err(Error::parse(0, "the guide is empty"));
+        }
+
+        let mut frequencies = vec![0; rules.shapes.len()];
+        let mut shapes = vec![];
+        for (i, round) in guide.iter().enumerate() {
+            let player = rules
+                .player
+                .iter()
+                .position(|e| *e == round.response)
+                .ok_or_else(|| {
+                    Error::parse(i + 1, format!("unexpected response {}", round.response))
+                })?;
+
+            frequencies[round.opponent] += 1;
+            shapes.push((round.opponent, player));
+        }
+
+        Ok(Self {
+            rules,
+            rounds: shapes.len(),
+            guide: shapes,
+            frequencies,
+            matches: 1000,
+            seed: 1,
+        })
+    }
+
+    fn opponent_shape(
+        &self,
+        opponent: Opponent,
+        round: usize,
+        memory: &Memory,
+        rng: &mut Rng,
+    ) -> usize {
+        let n = self.rules.shapes.len();
+
+        match opponent {
+            Opponent::Guide => self.guide[round % self.guide.len()].0,
+            Opponent::Uniform => rng.below(n),
+            Opponent::Biased => rng.weighted(&self.frequencies),
+            Opponent::RepeatLastWinner => match memory.last {
+                // on a draw both played the winning shape
+                Some((theirs, ours)) => match self.rules.outcome(ours, theirs) {
+                    Outcome::Win => ours,
+                    _ => theirs,
+                },
+                None => rng.below(n),
+            },
+        }
+    }
+
+    fn player_shape(
+        &self,
+        strategy: Strategy,
+        round: usize,
+        memory: &Memory,
+        rng: &mut Rng,
+    ) -> usize {
+        let predicted = match strategy {
+            Strategy::Fixed => return self.guide[round % self.guide.len()].1,
+            Strategy::CounterMostFrequent => most_frequent(&memory.counts),
+            Strategy::Markov => memory
+                .last
+                .and_then(|(last, _)| most_frequent(&memory.transitions[last])),
+        };
+
+        match predicted {
+            Some(shape) => self.rules.shape_for(shape, Outcome::Win),
+            // nothing to go on yet
+            None => rng.below(self.rules.shapes.len()),
+        }
+    }
+
+    pub fn simulate(&self, opponent: Opponent, strategy: Strategy) -> Summary {
+        let (mut score, mut wins, mut draws, mut losses) = (0u64, 0, 0, 0);
+
+        for i in 0..self.matches {
+            // every pairing sees the same seeds, and each side draws from its own stream so a
+            // random opponent plays the same shapes whatever the strategy draws
+            let mut seeds = Rng(self.seed.wrapping_add(i as u64));
+            let (mut their_rng, mut our_rng) = (Rng(seeds.next_u64()), Rng(seeds.next_u64()));
+            let mut memory = Memory::new(self.rules.shapes.len());
+
+            for round in 0..self.rounds {
+                let theirs = self.opponent_shape(opponent, round, &memory, &mut their_rng);
+                let ours = self.player_shape(strategy, round, &memory, &mut our_rng);
+
+                score += self.rules.score(ours, theirs) as u64;
+                match self.rules.outcome(ours, theirs) {
+                    Outcome::Win => wins += 1,
+                    Outcome::Draw => draws += 1,
+                    Outcome::Loss => losses += 1,
+                }
+                memory.remember(theirs, ours);
+            }
+        }
+
+        let matches = self.matches.max(1) as f64;
+        Summary {
+            opponent,
+            strategy,
+            mean_score: score as f64 / matches,
+            wins: wins as f64 / matches,
+            draws: draws as f64 / matches,
+            losses: losses as f64 / matches,
+        }
+    }
+}
+
+// what the player has seen of the opponent during a match
+struct Memory {
+    counts: Vec<usize>,
+    // transitions[a][b] - how often the opponent followed shape `a` with shape `b`
+    transitions: Vec<Vec<usize>>,
+    // the opponent's and the player's shape in the previous round
+    last: Option<(usize, usize)>,
+}
+
+impl Memory {
+    fn new(shapes: usize) -> Self {
+        Self {
+            counts: vec![0; shapes],
+            transitions: vec![vec![0; shapes]; shapes],
+            last: None,
+        }
+    }
+
+    fn remember(&mut self, theirs: usize, ours: usize) {
+        self.counts[theirs] += 1;
+        if let Some((previous, _)) = self.last {
+            self.transitions[previous][theirs] += 1;
+        }
+        self.last = Some((theirs, ours));
+    }
+}
+
+// the index with the highest count, `None` when everything is zero
+fn most_frequent(counts: &[usize]) -> Option<usize> {
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count > 0)
+        .max_by_key(|&(i, &count)| (count, usize::MAX - i))
+        .map(|(i, _)| i)
+}
@@ -0,0 +1,56 @@
+use day02::{interpretations, parse_guide, Interpretation, Reading, Rules};
+
+const SAMPLE: &str = "A Y\nB X\nC Z";
+
+fn mapping(interpretation: &Interpretation) -> Vec<(&str, &str)> {
+    let pairs = interpretation.mapping.iter();
+    pairs.map(|(a, b)| (a.as_str(), b.as_str())).collect()
+}
+
+#[test]
+fn sample_interpretations() {
+    let rules = Rules::default();
+    let rounds = parse_guide(SAMPLE, &rules).unwrap();
+    let interpretations = interpretations(&rounds, &rules).unwrap();
+
+    // every mapping of the three symbols to shapes and to outcomes
+    assert_eq!(interpretations.len(), 12);
+    let scores = interpretations
+        .iter()
+        .map(|e| e.score)
+        .collect::<Vec<u32>>();
+    assert!(scores.windows(2).all(|e| e[0] >= e[1]), "{scores:?}");
+
+    let puzzle = interpretations
+        .iter()
+        .filter(|e| e.is_puzzle_reading(&rules));
+    let puzzle = puzzle
+        .map(|e| (e.reading, e.score))
+        .collect::<Vec<(Reading, u32)>>();
+    assert_eq!(puzzle, [(Reading::Shapes, 15), (Reading::Outcomes, 12)]);
+
+    let best = &interpretations[0];
+    assert_eq!((best.reading, best.score), (Reading::Shapes, 24));
+    assert_eq!(
+        mapping(best),
+        [("X", "scissors"), ("Y", "paper"), ("Z", "rock")]
+    );
+
+    let worst = interpretations.last().unwrap();
+    assert_eq!((worst.reading, worst.score), (Reading::Shapes, 6));
+    assert_eq!(
+        mapping(worst),
+        [("X", "rock"), ("Y", "scissors"), ("Z", "paper")]
+    );
+}
+
+#[test]
+fn unreadable_response() {
+    let rules = Rules::default();
+    let rounds = parse_guide("A Y\nB Q", &rules).unwrap();
+
+    assert_eq!(
+        interpretations(&rounds, &rules).unwrap_err().to_string(),
+        "invalid input on line 2: unexpected response Q"
+    );
+}
@@ -0,0 +1,110 @@
+use aoc::params::{ParamError, Params};
+use day02::{Outcome, Rules};
+
+fn rules(config: &str) -> Result<Rules, ParamError> {
+    Rules::from_params(&mut Params::parse(config).unwrap())
+}
+
+fn shape(rules: &Rules, name: &str) -> usize {
+    rules.shapes.iter().position(|e| e == name).unwrap()
+}
+
+// every (winner, loser) pair of the rules
+fn wins(rules: &Rules) -> Vec<(&str, &str)> {
+    let n = rules.shapes.len();
+    let mut result = vec![];
+    for player in 0..n {
+        for opponent in 0..n {
+            if rules.outcome(player, opponent) == Outcome::Win {
+                result.push((
+                    rules.shapes[player].as_str(),
+                    rules.shapes[opponent].as_str(),
+                ));
+            }
+        }
+    }
+    result.sort();
+    result
+}
+
+fn assert_invalid(config: &str, key: &str) {
+    match rules(config) {
+        Err(ParamError::Invalid { key: invalid, .. }) => assert_eq!(invalid, key, "{config}"),
+        Err(e) => panic!("{config}: unexpected error {e}"),
+        Ok(_) => panic!("{config}: expected invalid {key}"),
+    }
+}
+
+#[test]
+fn rock_paper_scissors() {
+    let rules = Rules::default();
+    let [rock, paper, scissors] = ["rock", "paper", "scissors"].map(|e| shape(&rules, e));
+
+    assert_eq!(
+        wins(&rules),
+        [
+            ("paper", "rock"),
+            ("rock", "scissors"),
+            ("scissors", "paper")
+        ]
+    );
+    for shape in [rock, paper, scissors] {
+        assert_eq!(rules.outcome(shape, shape), Outcome::Draw);
+    }
+    assert_eq!(rules.outcome(rock, paper), Outcome::Loss);
+
+    assert_eq!(rules.shape_for(rock, Outcome::Win), paper);
+    assert_eq!(rules.shape_for(rock, Outcome::Draw), rock);
+    assert_eq!(rules.shape_for(rock, Outcome::Loss), scissors);
+    assert_eq!(rules.shape_for(scissors, Outcome::Win), rock);
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let rules = rules(include_str!("../rpsls.toml")).unwrap();
+    let [rock, spock, paper, lizard, scissors] =
+        ["rock", "spock", "paper", "lizard", "scissors"].map(|e| shape(&rules, e));
+
+    assert_eq!(
+        wins(&rules),
+        [
+            ("lizard", "paper"),
+            ("lizard", "spock"),
+            ("paper", "rock"),
+            ("paper", "spock"),
+            ("rock", "lizard"),
+            ("rock", "scissors"),
+            ("scissors", "lizard"),
+            ("scissors", "paper"),
+            ("spock", "rock"),
+            ("spock", "scissors"),
+        ]
+    );
+    assert_eq!(rules.outcome(lizard, lizard), Outcome::Draw);
+
+    // two shapes reach each outcome but a draw, the best scoring one is played
+    assert_eq!(rules.shape_for(rock, Outcome::Win), spock);
+    assert_eq!(rules.shape_for(rock, Outcome::Loss), lizard);
+    assert_eq!(rules.shape_for(rock, Outcome::Draw), rock);
+    assert_eq!(rules.shape_for(spock, Outcome::Win), lizard);
+    assert_eq!(rules.shape_for(spock, Outcome::Loss), scissors);
+    assert_eq!(rules.shape_for(paper, Outcome::Loss), spock);
+
+    assert_eq!(rules.score(spock, rock), 5 + 6);
+    assert_eq!(rules.score(scissors, rock), 3);
+}
+
+#[test]
+fn invalid_rules() {
+    assert_invalid("shapes = rock paper", "shapes");
+    assert_invalid(
+        "shapes = a b c d\nopponent = A B C D\nplayer = W X Y Z",
+        "shapes",
+    );
+    assert_invalid("opponent = A B", "opponent");
+    assert_invalid("player = X Y Z W", "player");
+    assert_invalid("shape_scores = 1 2", "shape_scores");
+    assert_invalid("shape_scores = 1 two 3", "shape_scores");
+    assert_invalid("outcomes = X Y", "outcomes");
+    assert_invalid("win = lots", "win");
+}
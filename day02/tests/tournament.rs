@@ -0,0 +1,75 @@
+use day02::{parse_guide, Opponent, Rules, Strategy, Summary, Tournament};
+
+const SAMPLE: &str = "A Y\nB X\nC Z";
+
+fn tournament<'a>(rules: &'a Rules, seed: u64) -> Tournament<'a> {
+    let rounds = parse_guide(SAMPLE, rules).unwrap();
+    let mut tournament = Tournament::new(rules, &rounds).unwrap();
+    tournament.matches = 200;
+    tournament.rounds = 50;
+    tournament.seed = seed;
+    tournament
+}
+
+fn summary(opponent: Opponent, strategy: Strategy, results: [f64; 4]) -> Summary {
+    let [mean_score, wins, draws, losses] = results;
+    Summary {
+        opponent,
+        strategy,
+        mean_score,
+        wins,
+        draws,
+        losses,
+    }
+}
+
+// pins the simulation for a seed, a change to how the sides draw their shapes shows up here
+#[test]
+fn seeded_results() {
+    let rules = Rules::default();
+    let tournament = tournament(&rules, 7);
+
+    let expected = [
+        (Opponent::Guide, Strategy::Fixed, [249.0, 17.0, 16.0, 17.0]),
+        (
+            Opponent::Guide,
+            Strategy::Markov,
+            [388.365, 47.25, 1.28, 1.47],
+        ),
+        (
+            Opponent::Uniform,
+            Strategy::Fixed,
+            [247.485, 16.34, 16.815, 16.845],
+        ),
+        (
+            Opponent::Uniform,
+            Strategy::CounterMostFrequent,
+            [251.78, 16.68, 16.875, 16.445],
+        ),
+        (
+            Opponent::Uniform,
+            Strategy::Markov,
+            [253.305, 16.76, 16.875, 16.365],
+        ),
+        (
+            Opponent::RepeatLastWinner,
+            Strategy::CounterMostFrequent,
+            [281.295, 10.59, 39.085, 0.325],
+        ),
+    ];
+    for (opponent, strategy, results) in expected {
+        assert_eq!(
+            tournament.simulate(opponent, strategy),
+            summary(opponent, strategy, results)
+        );
+    }
+}
+
+#[test]
+fn seeds_decide_the_results() {
+    let rules = Rules::default();
+    let simulate = |seed| tournament(&rules, seed).simulate(Opponent::Uniform, Strategy::Markov);
+
+    assert_eq!(simulate(7), simulate(7));
+    assert_ne!(simulate(7), simulate(8));
+}
@@ -0,0 +1,279 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+use std::fmt;
+use std::str::FromStr;
+
+mod regroup;
+pub use regroup::{regroup, Group, Regrouping};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // any problem with a rucksack or a group is an error
+    Strict,
+    // rucksacks and groups without exactly one common item score nothing
+    Lenient,
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode {
+            "strict" => Ok(Mode::Strict),
+            "lenient" => Ok(Mode::Lenient),
+            _ => Err(format!("Unknown mode {mode}!")),
+        }
+    }
+}
+
+pub struct Config {
+    pub group_size: usize,
+    pub mode: Mode,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            group_size: 3,
+            mode: Mode::Strict,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        let group_size = params.take("group_size", default.group_size)?;
+        if group_size == 0 {
+            return Err(ParamError::Invalid {
+                key: "group_size".to_string(),
+                value: group_size.to_string(),
+            });
+        }
+
+        Ok(Self {
+            group_size,
+            mode: params.take("mode", default.mode)?,
+        })
+    }
+}
+
+fn priority(item: u8) -> Option<u8> {
+    match item {
+        b'a'..=b'z' => Some(item - b'a' + 1),
+        b'A'..=b'Z' => Some(item - b'A' + 27),
+        _ => None,
+    }
+}
+
+// bit `n` is set when the item with priority `n` is present, anything but letters is ignored
+pub fn priority_mask(items: &str) -> u64 {
+    items
+        .bytes()
+        .filter_map(priority)
+        .fold(0, |mask, e| mask | 1 << e)
+}
+
+// the items every one of the rucksacks (or compartments) contains
+pub fn common_items(groups: &[&str]) -> u64 {
+    match groups.split_first() {
+        Some((first, rest)) => rest
+            .iter()
+            .fold(priority_mask(first), |mask, e| mask & priority_mask(e)),
+        None => 0,
+    }
+}
+
+fn items(mask: u64) -> Vec<char> {
+    (b'a'..=b'z')
+        .chain(b'A'..=b'Z')
+        .filter(|&e| priority(e).is_some_and(|p| mask & 1 << p != 0))
+        .map(char::from)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    OddLength,
+    InvalidItem(char),
+    NoCommonItem,
+    MultipleCommonItems(Vec<char>),
+    NoBadge,
+    MultipleBadges(Vec<char>),
+    // the number of elves in the last group
+    IncompleteGroup(usize),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let list = |items: &[char]| items.iter().collect::<String>();
+
+        match self {
+            Problem::OddLength => write!(f, "the compartments differ in size"),
+            Problem::InvalidItem(item) => write!(f, "{item:?} is not an item"),
+            Problem::NoCommonItem => write!(f, "no item in both compartments"),
+            Problem::MultipleCommonItems(items) => {
+                write!(f, "several items in both compartments: {}", list(items))
+            }
+            Problem::NoBadge => write!(f, "no badge in the group"),
+            Problem::MultipleBadges(items) => {
+                write!(f, "several badges in the group: {}", list(items))
+            }
+            Problem::IncompleteGroup(elves) => write!(f, "incomplete group of {elves}"),
+        }
+    }
+}
+
+// a problem and the line it was found on - the first line of a group for group problems
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub line: usize,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.problem)
+    }
+}
+
+fn item_issues(line: usize, items: &str) -> Vec<Issue> {
+    items
+        .chars()
+        .filter(|e| !e.is_ascii_alphabetic())
+        .map(|e| Issue {
+            line,
+            problem: Problem::InvalidItem(e),
+        })
+        .collect()
+}
+
+// scores a rucksack in part 1 or a group in part 2, problems with the items themselves aside
+fn score_common(
+    common: u64,
+    none: Problem,
+    several: fn(Vec<char>) -> Problem,
+) -> Result<u32, Problem> {
+    match common.count_ones() {
+        0 => Err(none),
+        1 => Ok(common.trailing_zeros()),
+        _ => Err(several(items(common))),
+    }
+}
+
+fn score_rucksack(line: usize, rucksack: &str) -> (u32, Vec<Issue>) {
+    let mut issues = item_issues(line, rucksack);
+    if !rucksack.len().is_multiple_of(2) {
+        issues.push(Issue {
+            line,
+            problem: Problem::OddLength,
+        });
+    }
+
+    // `split_at` would panic inside a multi-byte character
+    let middle = (0..=rucksack.len() / 2)
+        .rev()
+        .find(|&e| rucksack.is_char_boundary(e))
+        .unwrap_or(0);
+    let (left, right) = rucksack.split_at(middle);
+
+    let common = common_items(&[left, right]);
+    match score_common(common, Problem::NoCommonItem, Problem::MultipleCommonItems) {
+        Ok(score) => (score, issues),
+        Err(problem) => {
+            issues.push(Issue { line, problem });
+            (0, issues)
+        }
+    }
+}
+
+fn score_group(line: usize, group: &[&str], group_size: usize) -> (u32, Vec<Issue>) {
+    let mut issues = group
+        .iter()
+        .enumerate()
+        .flat_map(|(i, e)| item_issues(line + i, e))
+        .collect::<Vec<Issue>>();
+
+    if group.len() < group_size {
+        issues.push(Issue {
+            line,
+            problem: Problem::IncompleteGroup(group.len()),
+        });
+        return (0, issues);
+    }
+
+    match score_common(
+        common_items(group),
+        Problem::NoBadge,
+        Problem::MultipleBadges,
+    ) {
+        Ok(score) => (score, issues),
+        Err(problem) => {
+            issues.push(Issue { line, problem });
+            (0, issues)
+        }
+    }
+}
+
+fn score_all(input: &str, part: Part, group_size: usize) -> (u32, Vec<Issue>) {
+    let mut result = 0;
+    let mut issues = vec![];
+
+    match part {
+        Part::One => {
+            for (i, line) in input.lines().enumerate() {
+                let (score, rucksack_issues) = score_rucksack(i + 1, line);
+                result += score;
+                issues.extend(rucksack_issues);
+            }
+        }
+        Part::Two => {
+            let lines = input.lines().collect::<Vec<&str>>();
+
+            for (i, group) in lines.chunks(group_size).enumerate() {
+                let (score, group_issues) = score_group(i * group_size + 1, group, group_size);
+                result += score;
+                issues.extend(group_issues);
+            }
+        }
+    }
+
+    (result, issues)
+}
+
+// every problem with the rucksacks (part 1) and the groups (part 2), by line
+pub fn validate(input: &str, config: &Config) -> Vec<Issue> {
+    let (_, mut issues) = score_all(input, Part::One, config.group_size.max(1));
+    let (_, group_issues) = score_all(input, Part::Two, config.group_size.max(1));
+
+    // invalid items are found by both parts
+    for issue in group_issues {
+        if !issues.contains(&issue) {
+            issues.push(issue);
+        }
+    }
+    issues.sort_by_key(|e| e.line);
+
+    issues
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    if config.group_size == 0 {
+        return Err(Error::unsolvable("groups need at least one elf"));
+    }
+
+    let (result, issues) = score_all(input, part, config.group_size);
+
+    if config.mode == Mode::Strict {
+        if let Some(issue) = issues.first() {
+            return Err(Error::parse(issue.line, issue.problem.to_string()));
+        }
+    }
+
+    Ok(result.into())
+}
@@ -1,62 +1,68 @@
+use aoc::params::Params;
+use aoc::Part;
+use day03::Config;
 use std::env;
 use std::fs;
-
-fn score(char: &u8) -> u8 {
-    return match char {
-        b'a'..=b'z' => char - b'a' + 1,
-        b'A'..=b'Z' => char - b'A' + 27,
-        _ => unimplemented!(),
-    };
-}
-
-fn score_common_char_2(left: &str, right: &str) -> u8 {
-    for char in left.as_bytes() {
-        // TODO
-        if right.as_bytes().contains(char) {
-            return score(char);
-        }
-    }
-    unimplemented!();
-}
-
-fn score_common_char_3(first: &str, second: &str, third: &str) -> u8 {
-    for char in first.as_bytes() {
-        if second.as_bytes().contains(char) && third.as_bytes().contains(char) {
-            return score(char);
-        }
-    }
-    unimplemented!();
-}
+use std::process;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut params = Params::from_args(env::args()).unwrap_or_else(|e| panic!("{e}"));
+    let config = Config::from_params(&mut params).unwrap_or_else(|e| panic!("{e}"));
+    // lists every problematic rucksack and group instead of printing the answers
+    let validate = params.flag("validate").unwrap_or_else(|e| panic!("{e}"));
+    // searches for another grouping of the elves where every group has a badge
+    let regroup = params.flag("regroup").unwrap_or_else(|e| panic!("{e}"));
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
 
     let file_path = args
         .get(1)
         .expect("Should pass a file path as the second parameter.");
 
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    let lines = contents.lines();
-    let mut result: u32 = 0;
 
-    for line in lines {
-        let (left, right) = line.split_at(line.len() / 2);
-
-        result += score_common_char_2(left, right) as u32;
+    if validate {
+        let issues = day03::validate(&contents, &config);
+        if issues.is_empty() {
+            println!("No problems found.");
+        }
+        for issue in issues {
+            println!("{issue}");
+        }
+        return;
     }
 
-    println!("part 1: {}", result);
-
-    let mut lines = contents.lines().peekable();
-    let mut result: u32 = 0;
-
-    while lines.peek().is_some() {
-        result += score_common_char_3(
-            lines.next().unwrap(),
-            lines.next().unwrap(),
-            lines.next().unwrap(),
-        ) as u32;
+    if regroup {
+        match day03::regroup(&contents, config.group_size) {
+            Ok(regrouping) => {
+                for group in &regrouping.groups {
+                    let lines = group.lines.iter().map(|e| e.to_string());
+                    println!(
+                        "lines {}: {}",
+                        lines.collect::<Vec<String>>().join(", "),
+                        group.badge
+                    );
+                }
+                println!("priority sum: {}", regrouping.priority_sum);
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        return;
     }
 
-    println!("part 2: {}", result);
+    let mut failed = false;
+    for part in [Part::One, Part::Two] {
+        match day03::solve_with(&contents, part, &config) {
+            Ok(answer) => println!("{part}: {answer}"),
+            Err(e) => {
+                eprintln!("{part}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
 }
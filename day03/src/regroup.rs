@@ -0,0 +1,182 @@
+use crate::{items, priority_mask};
+use aoc::Error;
+
+// a group of elves - line numbers of their rucksacks - and the one item all of them carry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub lines: Vec<usize>,
+    pub badge: char,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regrouping {
+    pub groups: Vec<Group>,
+    pub priority_sum: u32,
+}
+
+struct Search {
+    used: Vec<bool>,
+    group_size: usize,
+    // the members of every group with exactly one common item, `group_size` elves each
+    candidates: Vec<usize>,
+    badges: Vec<u64>,
+    // the candidates each elf is a member of
+    by_elf: Vec<Vec<usize>>,
+    // the chosen candidates
+    groups: Vec<usize>,
+}
+
+impl Search {
+    fn new(masks: &[u64], group_size: usize) -> Self {
+        let mut search = Search {
+            used: vec![false; masks.len()],
+            group_size,
+            candidates: vec![],
+            badges: vec![],
+            by_elf: vec![vec![]; masks.len()],
+            groups: vec![],
+        };
+        search.find_candidates(masks, &mut vec![], u64::MAX);
+        search
+    }
+
+    // every group of elves whose rucksacks share exactly one item, members in input order
+    fn find_candidates(&mut self, masks: &[u64], members: &mut Vec<usize>, common: u64) {
+        if members.len() == self.group_size {
+            if common.count_ones() == 1 {
+                for &member in members.iter() {
+                    self.by_elf[member].push(self.badges.len());
+                }
+                self.candidates.extend_from_slice(members);
+                self.badges.push(common);
+            }
+            return;
+        }
+
+        let start = members.last().map_or(0, |e| e + 1);
+        for next in start..masks.len() {
+            // no common item now means no badge later
+            let common = common & masks[next];
+            if common == 0 {
+                continue;
+            }
+
+            members.push(next);
+            self.find_candidates(masks, members, common);
+            members.pop();
+        }
+    }
+
+    fn members(&self, candidate: usize) -> &[usize] {
+        &self.candidates[candidate * self.group_size..(candidate + 1) * self.group_size]
+    }
+
+    fn is_free(&self, candidate: usize) -> bool {
+        self.members(candidate).iter().all(|&e| !self.used[e])
+    }
+
+    fn mark(&mut self, candidate: usize, used: bool) {
+        for i in candidate * self.group_size..(candidate + 1) * self.group_size {
+            self.used[self.candidates[i]] = used;
+        }
+    }
+
+    // every unused elf still has to join one of its free groups - the elf with the fewest of them
+    // is grouped next, an elf without any means there is no grouping from here
+    fn next_group(&mut self) -> bool {
+        let mut most_constrained: Option<(usize, usize)> = None;
+
+        for elf in (0..self.used.len()).filter(|&e| !self.used[e]) {
+            // counting past the fewest so far does not change the choice
+            let free = self.by_elf[elf]
+                .iter()
+                .filter(|&&e| self.is_free(e))
+                .take(most_constrained.map_or(usize::MAX, |(_, e)| e))
+                .count();
+            if free == 0 {
+                return false;
+            }
+            if most_constrained.is_none_or(|(_, e)| free < e) {
+                most_constrained = Some((elf, free));
+            }
+        }
+
+        let Some((elf, _)) = most_constrained else {
+            return true;
+        };
+
+        for i in 0..self.by_elf[elf].len() {
+            let candidate = self.by_elf[elf][i];
+            if !self.is_free(candidate) {
+                continue;
+            }
+
+            self.mark(candidate, true);
+            self.groups.push(candidate);
+            if self.next_group() {
+                return true;
+            }
+            self.groups.pop();
+            self.mark(candidate, false);
+        }
+
+        false
+    }
+}
+
+// partitions the elves into groups with exactly one common item each, a valid grouping of the
+// input itself is returned as is
+pub fn regroup(input: &str, group_size: usize) -> Result<Regrouping, Error> {
+    if group_size == 0 {
+        return Err(Error::unsolvable("groups need at least one elf"));
+    }
+
+    let masks = input.lines().map(priority_mask).collect::<Vec<u64>>();
+    if !masks.len().is_multiple_of(group_size) {
+        return Err(Error::unsolvable(format!(
+            "{} elves cannot be split into groups of {group_size}",
+            masks.len()
+        )));
+    }
+
+    // the input's own groups need no search
+    let in_order = masks
+        .chunks(group_size)
+        .enumerate()
+        .map(|(i, group)| {
+            let members = (i * group_size..(i + 1) * group_size).collect::<Vec<usize>>();
+            (members, group.iter().fold(u64::MAX, |common, e| common & e))
+        })
+        .collect::<Vec<(Vec<usize>, u64)>>();
+
+    let groups = if in_order.iter().all(|(_, badge)| badge.count_ones() == 1) {
+        in_order
+    } else {
+        let mut search = Search::new(&masks, group_size);
+        if !search.next_group() {
+            return Err(Error::unsolvable("no grouping gives every group a badge"));
+        }
+
+        let mut groups = search
+            .groups
+            .iter()
+            .map(|&e| (search.members(e).to_vec(), search.badges[e]))
+            .collect::<Vec<(Vec<usize>, u64)>>();
+        groups.sort_unstable();
+        groups
+    };
+
+    let priority_sum = groups.iter().map(|(_, e)| e.trailing_zeros()).sum();
+    let groups = groups
+        .into_iter()
+        .map(|(members, badge)| Group {
+            lines: members.iter().map(|e| e + 1).collect(),
+            badge: items(badge)[0],
+        })
+        .collect();
+
+    Ok(Regrouping {
+        groups,
+        priority_sum,
+    })
+}
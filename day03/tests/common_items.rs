@@ -0,0 +1,68 @@
+use aoc::{Answer, Part};
+use day03::{common_items, priority_mask, solve_with, Config, Mode};
+
+const SAMPLE: [&str; 6] = [
+    "vJrwpWtwJgWrhcsFMMfFFhFp",
+    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+    "PmmdzqPrVvPwwTWBwg",
+    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+    "ttgJtRGJQctTZtZT",
+    "CrZsJsPPZsGzwwsLwLmpwMDw",
+];
+
+fn config(group_size: usize) -> Config {
+    Config {
+        group_size,
+        mode: Mode::Strict,
+    }
+}
+
+#[test]
+fn masks_by_priority() {
+    assert_eq!(priority_mask(""), 0);
+    assert_eq!(priority_mask("aazZ"), 1 << 1 | 1 << 26 | 1 << 52);
+    // anything but letters is left out
+    assert_eq!(priority_mask("a1 -b"), 1 << 1 | 1 << 2);
+}
+
+#[test]
+fn common_items_of_two() {
+    // the compartments of the first sample rucksack share `p`
+    assert_eq!(common_items(&["vJrwpWtwJgWr", "hcsFMMfFFhFp"]), 1 << 16);
+    assert_eq!(common_items(&["abc", "cba"]), 1 << 1 | 1 << 2 | 1 << 3);
+    assert_eq!(common_items(&["abc", "ABC"]), 0);
+}
+
+#[test]
+fn common_items_of_three() {
+    // the badges of the sample groups, `r` and `Z`
+    assert_eq!(common_items(&SAMPLE[..3]), 1 << 18);
+    assert_eq!(common_items(&SAMPLE[3..]), 1 << 52);
+}
+
+#[test]
+fn common_items_of_none_and_one() {
+    assert_eq!(common_items(&[]), 0);
+    assert_eq!(common_items(&["ab"]), priority_mask("ab"));
+}
+
+#[test]
+fn any_group_size() {
+    let sample = SAMPLE.join("\n");
+    assert_eq!(
+        solve_with(&sample, Part::Two, &config(3)).unwrap(),
+        Answer::Number(70)
+    );
+
+    let pairs = "aBcd\nBxyz\npqrs\nstuv";
+    assert_eq!(
+        solve_with(pairs, Part::Two, &config(2)).unwrap(),
+        Answer::Number(28 + 19)
+    );
+
+    let quads = "abcZ\nZdef\nghiZ\nZjkl";
+    assert_eq!(
+        solve_with(quads, Part::Two, &config(4)).unwrap(),
+        Answer::Number(52)
+    );
+}
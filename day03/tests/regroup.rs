@@ -0,0 +1,74 @@
+use day03::regroup;
+use std::time::{Duration, Instant};
+
+const SAMPLE: [&str; 6] = [
+    "vJrwpWtwJgWrhcsFMMfFFhFp",
+    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+    "PmmdzqPrVvPwwTWBwg",
+    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+    "ttgJtRGJQctTZtZT",
+    "CrZsJsPPZsGzwwsLwLmpwMDw",
+];
+
+#[test]
+fn input_grouping_is_kept() {
+    let regrouping = regroup(&SAMPLE.join("\n"), 3).unwrap();
+
+    let groups = regrouping.groups.iter();
+    let lines = groups.map(|e| (e.lines.clone(), e.badge));
+    assert_eq!(
+        lines.collect::<Vec<(Vec<usize>, char)>>(),
+        [(vec![1, 2, 3], 'r'), (vec![4, 5, 6], 'Z')]
+    );
+    assert_eq!(regrouping.priority_sum, 70);
+}
+
+#[test]
+fn shuffled_elves() {
+    let shuffled = [0, 3, 1, 4, 5, 2].map(|e| SAMPLE[e]).join("\n");
+    let regrouping = regroup(&shuffled, 3).unwrap();
+
+    let groups = regrouping.groups.iter();
+    let lines = groups.map(|e| (e.lines.clone(), e.badge));
+    assert_eq!(
+        lines.collect::<Vec<(Vec<usize>, char)>>(),
+        [(vec![1, 3, 6], 'r'), (vec![2, 4, 5], 'Z')]
+    );
+    assert_eq!(regrouping.priority_sum, 70);
+}
+
+// rucksacks with 16 of the items `a` to `x`, the same for every run
+fn rucksacks(count: usize) -> Vec<String> {
+    let mut state: u64 = 1;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+
+    (0..count)
+        .map(|_| {
+            (0..16)
+                .map(|_| (b'a' + (next() % 24) as u8) as char)
+                .collect()
+        })
+        .collect()
+}
+
+// nobody else carries `y`, so that elf cannot be grouped - found before searching through the
+// groupings of all the other elves
+#[test]
+fn no_grouping_is_found_quickly() {
+    let mut elves = rucksacks(297);
+    elves.extend(["zz", "zz", "yy"].map(String::from));
+
+    let start = Instant::now();
+    let result = regroup(&elves.join("\n"), 3);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "no solution: no grouping gives every group a badge"
+    );
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,86 @@
+use aoc::{Answer, Part};
+use day03::{solve_with, validate, Config, Issue, Mode, Problem};
+
+// a problem on every line but the last full group
+const RUCKSACKS: &str = "abca\nabc\nab1b\nabab\nxyzw\nmnop\nzyzx\nzqwq\nzkek\nmm";
+
+fn config(mode: Mode) -> Config {
+    Config {
+        group_size: 3,
+        mode,
+    }
+}
+
+fn issue(line: usize, problem: Problem) -> Issue {
+    Issue { line, problem }
+}
+
+#[test]
+fn every_problem_by_line() {
+    assert_eq!(
+        validate(RUCKSACKS, &config(Mode::Strict)),
+        [
+            issue(1, Problem::MultipleBadges(vec!['a', 'b'])),
+            issue(2, Problem::OddLength),
+            issue(2, Problem::NoCommonItem),
+            issue(3, Problem::InvalidItem('1')),
+            issue(4, Problem::MultipleCommonItems(vec!['a', 'b'])),
+            issue(4, Problem::NoBadge),
+            issue(5, Problem::NoCommonItem),
+            issue(6, Problem::NoCommonItem),
+            issue(10, Problem::IncompleteGroup(1)),
+        ]
+    );
+}
+
+#[test]
+fn problems_read_with_their_line() {
+    let issues = validate("ab1b\nabab", &config(Mode::Strict));
+    let issues = issues
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<String>>();
+
+    assert_eq!(
+        issues,
+        [
+            "line 1: '1' is not an item",
+            "line 1: incomplete group of 2",
+            "line 2: several items in both compartments: ab",
+        ]
+    );
+}
+
+#[test]
+fn strict_scoring_fails_on_the_first_problem() {
+    let strict = config(Mode::Strict);
+
+    assert_eq!(
+        solve_with(RUCKSACKS, Part::One, &strict)
+            .unwrap_err()
+            .to_string(),
+        "invalid input on line 2: the compartments differ in size"
+    );
+    assert_eq!(
+        solve_with("zyzx\nzqwq\nzkek\nmm", Part::Two, &strict)
+            .unwrap_err()
+            .to_string(),
+        "invalid input on line 4: incomplete group of 1"
+    );
+}
+
+#[test]
+fn lenient_scoring_skips_problems() {
+    let lenient = config(Mode::Lenient);
+
+    // a, b, z, q, k and m
+    assert_eq!(
+        solve_with(RUCKSACKS, Part::One, &lenient).unwrap(),
+        Answer::Number(1 + 2 + 26 + 17 + 11 + 13)
+    );
+    // only the group of lines 7 to 9 has a single badge, z
+    assert_eq!(
+        solve_with(RUCKSACKS, Part::Two, &lenient).unwrap(),
+        Answer::Number(26)
+    );
+}
@@ -0,0 +1,248 @@
+use aoc::{Answer, Error, Part};
+use std::ops::RangeInclusive;
+
+mod relation;
+mod roster;
+pub use relation::{classify, classify_pairs, Pair, Relation};
+pub use roster::{Assignment, Coverage, Roster};
+
+// an assignment of one or more dimensions - sections, a rectangle `1-3x4-6` or a box `1-3x4-6x2-2`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range<const N: usize>(pub [RangeInclusive<i32>; N]);
+
+impl<const N: usize> Range<N> {
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(|e| e.is_empty())
+    }
+}
+
+fn parse_interval(interval: &str) -> Option<RangeInclusive<i32>> {
+    match interval.split('-').collect::<Vec<&str>>()[..] {
+        [from, to] => Some(from.parse::<i32>().ok()?..=to.parse::<i32>().ok()?),
+        _ => None,
+    }
+}
+
+fn parse_range<const N: usize>(range: &str) -> Option<Range<N>> {
+    let intervals = range
+        .split('x')
+        .map(parse_interval)
+        .collect::<Option<Vec<RangeInclusive<i32>>>>()?;
+
+    Some(Range(intervals.try_into().ok()?))
+}
+
+// any number of comma separated assignments, all with `N` dimensions
+fn parse_line<const N: usize>(i: usize, line: &str) -> Result<Vec<Range<N>>, Error> {
+    line.split(',')
+        .map(parse_range)
+        .collect::<Option<Vec<Range<N>>>>()
+        .ok_or_else(|| Error::parse(i + 1, format!("range parsing error in {line}")))
+}
+
+// the dimensions of the first assignment, every other one has to match
+fn dimensions(input: &str) -> usize {
+    input
+        .split([',', '\n'])
+        .next()
+        .map(|e| e.split('x').count())
+        .unwrap_or(1)
+}
+
+fn compare_ranges<const N: usize>(first: &Range<N>, second: &Range<N>) -> (bool, bool) {
+    (
+        is_range_fully_contained(first, second) || is_range_fully_contained(second, first),
+        is_range_partially_overlapping(first, second),
+    )
+}
+
+fn is_range_fully_contained<const N: usize>(parent: &Range<N>, child: &Range<N>) -> bool {
+    parent
+        .0
+        .iter()
+        .zip(&child.0)
+        .all(|(parent, child)| parent.contains(child.start()) && parent.contains(child.end()))
+}
+
+// boxes overlap when they overlap in every dimension
+fn is_range_partially_overlapping<const N: usize>(first: &Range<N>, second: &Range<N>) -> bool {
+    let overlapping = |parent: &RangeInclusive<i32>, child: &RangeInclusive<i32>| {
+        parent.contains(child.start()) || parent.contains(child.end())
+    };
+
+    first
+        .0
+        .iter()
+        .zip(&second.0)
+        .all(|(first, second)| overlapping(first, second) || overlapping(second, first))
+}
+
+// the cells covered by at least one of the boxes - coordinate compression along the first
+// dimension, recursing into the slabs between consecutive boundaries
+fn covered_cells(boxes: &[&[RangeInclusive<i32>]]) -> u64 {
+    let dimensions = match boxes.first() {
+        Some(first) => first.len(),
+        None => return 0,
+    };
+    if dimensions == 0 {
+        return 1;
+    }
+
+    let start = |e: &[RangeInclusive<i32>]| *e[0].start() as i64;
+    let end = |e: &[RangeInclusive<i32>]| *e[0].end() as i64;
+
+    if dimensions == 1 {
+        // the union of intervals needs no slabs
+        let mut intervals = boxes
+            .iter()
+            .map(|e| (start(e), end(e)))
+            .collect::<Vec<(i64, i64)>>();
+        intervals.sort_unstable();
+
+        let mut result = 0;
+        let mut covered = i64::MIN;
+        for (from, to) in intervals {
+            if to > covered {
+                result += (to - from.max(covered + 1) + 1) as u64;
+                covered = to;
+            }
+        }
+        return result;
+    }
+
+    let mut bounds = boxes
+        .iter()
+        .flat_map(|e| [start(e), end(e) + 1])
+        .collect::<Vec<i64>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let mut result = 0;
+    for slab in bounds.windows(2) {
+        let active = boxes
+            .iter()
+            .filter(|e| start(e) <= slab[0] && slab[0] <= end(e))
+            .map(|e| &e[1..])
+            .collect::<Vec<&[RangeInclusive<i32>]>>();
+
+        if !active.is_empty() {
+            result += (slab[1] - slab[0]) as u64 * covered_cells(&active);
+        }
+    }
+
+    result
+}
+
+fn count_overlaps<const N: usize>(input: &str, part: Part) -> Result<Answer, Error> {
+    let mut full_overlap: u32 = 0;
+    let mut partial_overlap: u32 = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        let ranges = parse_line::<N>(i, line)?;
+
+        // a line counts once if any pair of its assignments does
+        let (mut full, mut partial) = (false, false);
+        for (j, first) in ranges.iter().enumerate() {
+            for second in &ranges[j + 1..] {
+                let (pair_full, pair_partial) = compare_ranges(first, second);
+                full |= pair_full;
+                partial |= pair_partial;
+            }
+        }
+
+        if full {
+            full_overlap += 1;
+        }
+        if partial {
+            partial_overlap += 1;
+        }
+    }
+
+    match part {
+        Part::One => Ok(full_overlap.into()),
+        Part::Two => Ok(partial_overlap.into()),
+    }
+}
+
+fn count_covered<const N: usize>(input: &str) -> Result<u64, Error> {
+    let mut ranges = vec![];
+    for (i, line) in input.lines().enumerate() {
+        ranges.extend(parse_line::<N>(i, line)?);
+    }
+
+    let boxes = ranges
+        .iter()
+        .filter(|e| !e.is_empty())
+        .map(|e| &e.0[..])
+        .collect::<Vec<&[RangeInclusive<i32>]>>();
+
+    Ok(covered_cells(&boxes))
+}
+
+fn unsupported_dimensions(dimensions: usize) -> Error {
+    Error::parse(1, format!("{dimensions} dimensions, expected 1 to 3"))
+}
+
+// the sections, area or volume covered by at least one assignment
+pub fn covered(input: &str) -> Result<u64, Error> {
+    match dimensions(input) {
+        1 => count_covered::<1>(input),
+        2 => count_covered::<2>(input),
+        3 => count_covered::<3>(input),
+        dimensions => Err(unsupported_dimensions(dimensions)),
+    }
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    match dimensions(input) {
+        1 => count_overlaps::<1>(input, part),
+        2 => count_overlaps::<2>(input, part),
+        3 => count_overlaps::<3>(input, part),
+        dimensions => Err(unsupported_dimensions(dimensions)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn covered_sections() {
+        assert_eq!(covered("2-4,6-8\n2-3,4-5"), Ok(7));
+        // a gap and an elf covering nothing new
+        assert_eq!(covered("1-3,6-8\n2-2"), Ok(6));
+        assert_eq!(covered("1-9,2-3,5-5"), Ok(9));
+        assert_eq!(covered("5-3"), Ok(0));
+        assert_eq!(covered_cells(&[]), 0);
+    }
+
+    #[test]
+    fn covered_area() {
+        // two 3x3 squares sharing a 2x2 one
+        assert_eq!(covered("1-3x1-3,2-4x2-4"), Ok(14));
+        assert_eq!(covered("1-2x1-2,3-4x1-2"), Ok(8));
+        assert_eq!(covered("1-2x1-2\n5-6x5-6"), Ok(8));
+        assert_eq!(covered("1-3x1-3,2-2x2-2,3-1x1-3"), Ok(9));
+    }
+
+    #[test]
+    fn covered_volume() {
+        // two 3x3x3 boxes sharing a 2x2x2 one
+        assert_eq!(covered("1-3x1-3x1-3,2-4x2-4x2-4"), Ok(46));
+        assert_eq!(covered("1-4x1-4x1-4,2-3x2-3x2-3"), Ok(64));
+        assert_eq!(covered("1-1x1-1x1-1\n1-1x1-1x2-2"), Ok(2));
+    }
+
+    #[test]
+    fn boxes_overlap_in_every_dimension() {
+        let input = "1-3x1-3,2-4x2-4\n1-4x1-4,2-3x2-3\n1-3x1-3,2-4x5-6";
+        assert_eq!(solve(input, Part::One), Ok(Answer::Number(1)));
+        assert_eq!(solve(input, Part::Two), Ok(Answer::Number(2)));
+    }
+
+    #[test]
+    fn dimensions_must_match() {
+        assert!(covered("1-3x1-3,2-4").is_err());
+        assert!(covered("1-3x1-3x1-3x1-3").is_err());
+    }
+}
@@ -1,71 +1,161 @@
+use aoc::params::Params;
+use aoc::Part;
+use day04::{Coverage, Pair, Relation, Roster};
 use std::env;
 use std::fs;
 use std::ops::RangeInclusive;
+use std::process;
 
-fn parse_range(range: &str) -> RangeInclusive<i32> {
-    if let &[from, to] = range
-        .split("-")
-        .collect::<Vec<&str>>()
-        .iter()
-        .map(|&e| e.parse::<i32>().unwrap())
-        .collect::<Vec<i32>>()
-        .as_slice()
-    {
-        return from..=to;
+fn format_sections(sections: &RangeInclusive<i32>) -> String {
+    if sections.start() == sections.end() {
+        sections.start().to_string()
+    } else {
+        format!("{}-{}", sections.start(), sections.end())
     }
-
-    unimplemented!("range parsing error");
 }
 
-fn compare_ranges(first: &str, second: &str) -> (bool, bool) {
-    let first_range = parse_range(first);
-    let second_range = parse_range(second);
+fn format_list(sections: &[RangeInclusive<i32>]) -> String {
+    if sections.is_empty() {
+        return "none".to_string();
+    }
 
-    (
-        is_range_fully_contained(&first_range, &second_range)
-            || is_range_fully_contained(&second_range, &first_range),
-        is_range_partially_overlapping(&first_range, &second_range)
-            || is_range_partially_overlapping(&second_range, &first_range),
-    )
+    sections
+        .iter()
+        .map(format_sections)
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
-fn is_range_fully_contained(parent: &RangeInclusive<i32>, child: &RangeInclusive<i32>) -> bool {
-    parent.contains(child.start()) && parent.contains(child.end())
+fn print_coverage(roster: &Roster, coverage: &Coverage, limit: usize) {
+    println!(
+        "elves: {}, sections {}",
+        roster.assignments.len(),
+        format_sections(&coverage.sections)
+    );
+    println!("uncovered: {}", format_list(&coverage.gaps));
+
+    let crowded = coverage
+        .crowded
+        .iter()
+        .map(|(sections, elves)| format!("{} ({elves})", format_sections(sections)))
+        .collect::<Vec<String>>();
+    if crowded.is_empty() {
+        println!("covered by more than {limit}: none");
+    } else {
+        println!("covered by more than {limit}: {}", crowded.join(", "));
+    }
+
+    println!(
+        "max depth: {} at {}",
+        coverage.max_depth,
+        format_list(&coverage.deepest)
+    );
+
+    println!("redundant elves: {}", coverage.redundant.len());
+    for &i in &coverage.redundant {
+        let assignment = &roster.assignments[i];
+        println!(
+            "  line {} elf {}: {}",
+            assignment.line,
+            assignment.elf,
+            format_sections(&assignment.sections)
+        );
+    }
 }
 
-fn is_range_partially_overlapping(
-    parent: &RangeInclusive<i32>,
-    child: &RangeInclusive<i32>,
-) -> bool {
-    parent.contains(child.start()) || parent.contains(child.end())
+fn print_pairs(pairs: &[Pair]) {
+    for pair in pairs {
+        let (first, second) = &pair.sections;
+        println!(
+            "line {} elves {}-{}: {},{} {} overlap {}{}",
+            pair.line,
+            pair.elves.0,
+            pair.elves.1,
+            format_sections(first),
+            format_sections(second),
+            pair.relation,
+            pair.overlap,
+            if pair.is_consistent() {
+                ""
+            } else {
+                " - inconsistent with the puzzle checks"
+            }
+        );
+    }
+
+    println!("pairs: {}", pairs.len());
+    for relation in Relation::ALL {
+        let count = pairs.iter().filter(|e| e.relation == relation).count();
+        println!("  {relation}: {count}");
+    }
+    println!(
+        "inconsistent: {}",
+        pairs.iter().filter(|e| !e.is_consistent()).count()
+    );
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut params = Params::from_args(env::args()).unwrap_or_else(|e| panic!("{e}"));
+    // analyzes all assignments together instead of printing the answers
+    let roster = params.flag("roster").unwrap_or_else(|e| panic!("{e}"));
+    // classifies every pair of assignments instead of printing the answers
+    let explain = params.flag("explain").unwrap_or_else(|e| panic!("{e}"));
+    // prints the sections, area or volume covered by at least one assignment
+    let covered = params.flag("covered").unwrap_or_else(|e| panic!("{e}"));
+    // sections shared by more elves than this are reported as crowded
+    let crowd: usize = params.take("crowd", 1).unwrap_or_else(|e| panic!("{e}"));
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
 
     let file_path = args
         .get(1)
         .expect("Should pass a file path as the second parameter.");
 
     let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    let lines = contents.lines();
-    let mut full_overlap: u32 = 0;
-    let mut partial_overlap: u32 = 0;
-
-    for line in lines {
-        if let &[first, second] = line.split(",").collect::<Vec<&str>>().as_slice() {
-            let (full, partial) = compare_ranges(first, second);
-            if full {
-                full_overlap += 1;
+
+    if explain {
+        match day04::classify_pairs(&contents) {
+            Ok(pairs) => print_pairs(&pairs),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
             }
-            if partial {
-                partial_overlap += 1;
+        }
+        return;
+    }
+
+    if covered {
+        match day04::covered(&contents) {
+            Ok(cells) => println!("covered: {cells}"),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
             }
-        } else {
-            unimplemented!("section parsing error");
         }
+        return;
     }
 
-    println!("full_overlap: {}", full_overlap);
-    println!("partial_overlap: {}", partial_overlap);
+    if roster {
+        match Roster::parse(&contents).and_then(|roster| Ok((roster.coverage(crowd)?, roster))) {
+            Ok((coverage, roster)) => print_coverage(&roster, &coverage, crowd),
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let mut failed = false;
+    for part in [Part::One, Part::Two] {
+        match day04::solve(&contents, part) {
+            Ok(answer) => println!("{part}: {answer}"),
+            Err(e) => {
+                eprintln!("{part}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
 }
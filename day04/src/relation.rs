@@ -0,0 +1,201 @@
+use crate::{compare_ranges, parse_line, Range};
+use aoc::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Identical,
+    FirstContainsSecond,
+    SecondContainsFirst,
+    // the ranges overlap and the first one starts before the second one
+    PartialLeft,
+    // the ranges overlap and the first one ends after the second one
+    PartialRight,
+    // no common section, but nothing in between either
+    Adjacent,
+    Disjoint,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 7] = [
+        Relation::Identical,
+        Relation::FirstContainsSecond,
+        Relation::SecondContainsFirst,
+        Relation::PartialLeft,
+        Relation::PartialRight,
+        Relation::Adjacent,
+        Relation::Disjoint,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Relation::Identical => "identical",
+            Relation::FirstContainsSecond => "first-contains-second",
+            Relation::SecondContainsFirst => "second-contains-first",
+            Relation::PartialLeft => "partial-left",
+            Relation::PartialRight => "partial-right",
+            Relation::Adjacent => "adjacent",
+            Relation::Disjoint => "disjoint",
+        }
+    }
+
+    pub fn is_contained(&self) -> bool {
+        matches!(
+            self,
+            Relation::Identical | Relation::FirstContainsSecond | Relation::SecondContainsFirst
+        )
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// the relation and the number of common sections - a range ending before it starts has none,
+// so it is disjoint from everything
+pub fn classify(first: &RangeInclusive<i32>, second: &RangeInclusive<i32>) -> (Relation, u32) {
+    let (a, b) = (*first.start() as i64, *first.end() as i64);
+    let (c, d) = (*second.start() as i64, *second.end() as i64);
+
+    if first.is_empty() || second.is_empty() {
+        return (Relation::Disjoint, 0);
+    }
+
+    let overlap = (b.min(d) - a.max(c) + 1).max(0) as u32;
+    let relation = if overlap == 0 {
+        if b + 1 == c || d + 1 == a {
+            Relation::Adjacent
+        } else {
+            Relation::Disjoint
+        }
+    } else if a == c && b == d {
+        Relation::Identical
+    } else if a <= c && d <= b {
+        Relation::FirstContainsSecond
+    } else if c <= a && b <= d {
+        Relation::SecondContainsFirst
+    } else if a < c {
+        Relation::PartialLeft
+    } else {
+        Relation::PartialRight
+    };
+
+    (relation, overlap)
+}
+
+// a pair of assignments on one line, classified next to what the puzzle's checks say about it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair {
+    pub line: usize,
+    // elves are numbered from 1 within their line
+    pub elves: (usize, usize),
+    pub sections: (RangeInclusive<i32>, RangeInclusive<i32>),
+    pub relation: Relation,
+    pub overlap: u32,
+    // what `compare_ranges` says - counted in part 1 and part 2
+    pub contained: bool,
+    pub overlapping: bool,
+}
+
+impl Pair {
+    // true when the puzzle's checks match the classification
+    pub fn is_consistent(&self) -> bool {
+        self.contained == self.relation.is_contained() && self.overlapping == (self.overlap > 0)
+    }
+}
+
+pub fn classify_pairs(input: &str) -> Result<Vec<Pair>, Error> {
+    let mut result = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let ranges = parse_line::<1>(i, line)?;
+
+        for (j, first) in ranges.iter().enumerate() {
+            for (k, second) in ranges.iter().enumerate().skip(j + 1) {
+                let (contained, overlapping) = compare_ranges(first, second);
+                let (Range([first]), Range([second])) = (first, second);
+                let (relation, overlap) = classify(first, second);
+
+                result.push(Pair {
+                    line: i + 1,
+                    elves: (j + 1, k + 1),
+                    sections: (first.clone(), second.clone()),
+                    relation,
+                    overlap,
+                    contained,
+                    overlapping,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+
+    #[test]
+    fn every_relation() {
+        let cases = [
+            ((2, 4), (2, 4), Relation::Identical, 3),
+            ((2, 8), (3, 7), Relation::FirstContainsSecond, 5),
+            ((2, 8), (2, 5), Relation::FirstContainsSecond, 4),
+            ((3, 7), (2, 8), Relation::SecondContainsFirst, 5),
+            ((6, 6), (4, 6), Relation::SecondContainsFirst, 1),
+            ((2, 6), (4, 8), Relation::PartialLeft, 3),
+            ((2, 4), (4, 6), Relation::PartialLeft, 1),
+            ((4, 8), (2, 6), Relation::PartialRight, 3),
+            ((2, 4), (5, 7), Relation::Adjacent, 0),
+            ((5, 7), (2, 4), Relation::Adjacent, 0),
+            ((2, 4), (6, 8), Relation::Disjoint, 0),
+            ((6, 8), (2, 4), Relation::Disjoint, 0),
+            // empty ranges share nothing, not even with a range around them
+            ((5, 3), (1, 9), Relation::Disjoint, 0),
+            ((1, 9), (5, 3), Relation::Disjoint, 0),
+            ((5, 3), (5, 3), Relation::Disjoint, 0),
+            (
+                (i32::MIN, i32::MAX),
+                (0, 0),
+                Relation::FirstContainsSecond,
+                1,
+            ),
+        ];
+
+        for ((a, b), (c, d), relation, overlap) in cases {
+            let (first, second) = (a..=b, c..=d);
+            assert_eq!(
+                classify(&first, &second),
+                (relation, overlap),
+                "{first:?} and {second:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn sample_pairs() {
+        let pairs = classify_pairs(SAMPLE).unwrap();
+
+        assert_eq!(
+            pairs
+                .iter()
+                .map(|e| (e.relation, e.overlap))
+                .collect::<Vec<(Relation, u32)>>(),
+            [
+                (Relation::Disjoint, 0),
+                (Relation::Adjacent, 0),
+                (Relation::PartialLeft, 1),
+                (Relation::FirstContainsSecond, 5),
+                (Relation::SecondContainsFirst, 1),
+                (Relation::PartialLeft, 3),
+            ]
+        );
+        assert!(pairs.iter().all(|e| e.is_consistent()));
+    }
+}
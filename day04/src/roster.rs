@@ -0,0 +1,217 @@
+use crate::{parse_line, Range};
+use aoc::Error;
+use std::ops::RangeInclusive;
+
+// the sections of one elf - elves are numbered from 1 within their line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub line: usize,
+    pub elf: usize,
+    pub sections: RangeInclusive<i32>,
+}
+
+// every assignment of the input taken together
+pub struct Roster {
+    pub assignments: Vec<Assignment>,
+}
+
+// sections from the first to the last one assigned to anybody
+pub struct Coverage {
+    pub sections: RangeInclusive<i32>,
+    // sections covered by nobody
+    pub gaps: Vec<RangeInclusive<i32>>,
+    // (sections, elves) where more elves than the limit share the sections
+    pub crowded: Vec<(RangeInclusive<i32>, usize)>,
+    pub max_depth: usize,
+    pub deepest: Vec<RangeInclusive<i32>>,
+    // indices of the most assignments that can be dropped without uncovering a section
+    pub redundant: Vec<usize>,
+}
+
+impl Roster {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut assignments = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            for (j, Range([sections])) in parse_line::<1>(i, line)?.into_iter().enumerate() {
+                assignments.push(Assignment {
+                    line: i + 1,
+                    elf: j + 1,
+                    sections,
+                });
+            }
+        }
+
+        Ok(Self { assignments })
+    }
+
+    // a sweep line over the starts and ends of the assignments - (sections, elves) for every
+    // stretch with the same number of elves, from the first to the last assigned section
+    fn depths(&self) -> Vec<(RangeInclusive<i32>, usize)> {
+        let mut events = vec![];
+        for assignment in &self.assignments {
+            let (start, end) = (*assignment.sections.start(), *assignment.sections.end());
+            if start <= end {
+                // the end is exclusive, so sections next to each other do not overlap
+                events.push((start as i64, 1));
+                events.push((end as i64 + 1, -1));
+            }
+        }
+        events.sort_unstable();
+
+        let mut result: Vec<(RangeInclusive<i32>, usize)> = vec![];
+        let mut depth: i64 = 0;
+        for (i, &(position, change)) in events.iter().enumerate() {
+            depth += change;
+
+            if let Some(&(next, _)) = events.get(i + 1) {
+                if next > position {
+                    let sections = position as i32..=(next - 1) as i32;
+                    match result.last_mut() {
+                        // one assignment ending where another starts leaves the depth as it was
+                        Some((last, last_depth))
+                            if *last_depth == depth as usize
+                                && *last.end() + 1 == position as i32 =>
+                        {
+                            *last = *last.start()..=*sections.end();
+                        }
+                        _ => result.push((sections, depth as usize)),
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    // the fewest assignments covering every covered section - greedy, always taking the
+    // assignment reaching furthest among the ones starting before the first uncovered section
+    fn minimal_cover(&self) -> Vec<usize> {
+        let mut order = (0..self.assignments.len())
+            .filter(|&e| !self.assignments[e].sections.is_empty())
+            .collect::<Vec<usize>>();
+        order.sort_by_key(|&e| *self.assignments[e].sections.start());
+
+        let start = |i: usize| *self.assignments[i].sections.start() as i64;
+        let end = |i: usize| *self.assignments[i].sections.end() as i64;
+
+        let mut result = vec![];
+        let mut covered: Option<i64> = None;
+        let mut i = 0;
+        while i < order.len() {
+            let needed = match covered {
+                Some(covered) if start(order[i]) <= covered + 1 => covered + 1,
+                // a gap, the next stretch starts
+                _ => start(order[i]),
+            };
+
+            let mut best = order[i];
+            while i < order.len() && start(order[i]) <= needed {
+                if end(order[i]) > end(best) {
+                    best = order[i];
+                }
+                i += 1;
+            }
+
+            if end(best) >= needed {
+                result.push(best);
+                covered = Some(end(best));
+            }
+        }
+
+        result
+    }
+
+    pub fn coverage(&self, limit: usize) -> Result<Coverage, Error> {
+        let depths = self.depths();
+        let (first, last) = match (depths.first(), depths.last()) {
+            (Some(first), Some(last)) => (*first.0.start(), *last.0.end()),
+            _ => return Err(Error::unsolvable("no sections assigned")),
+        };
+
+        let max_depth = depths.iter().map(|e| e.1).max().unwrap_or(0);
+        let select = |keep: &dyn Fn(usize) -> bool| {
+            depths
+                .iter()
+                .filter(|e| keep(e.1))
+                .map(|e| e.0.clone())
+                .collect::<Vec<RangeInclusive<i32>>>()
+        };
+
+        let cover = self.minimal_cover();
+
+        Ok(Coverage {
+            sections: first..=last,
+            gaps: select(&|depth| depth == 0),
+            crowded: depths.iter().filter(|e| e.1 > limit).cloned().collect(),
+            max_depth,
+            deepest: select(&|depth| depth == max_depth),
+            redundant: (0..self.assignments.len())
+                .filter(|e| !cover.contains(e))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a gap at 6-7, two elves from 2 to 4 and at 9, the 4-4 and 9-9 elves are not needed
+    // and 5-3 covers nothing
+    const ROSTER: &str = "1-3,2-5\n4-4\n8-9,9-9,5-3";
+
+    #[test]
+    fn depths() {
+        let roster = Roster::parse(ROSTER).unwrap();
+
+        assert_eq!(
+            roster.depths(),
+            [
+                (1..=1, 1),
+                (2..=4, 2),
+                (5..=5, 1),
+                (6..=7, 0),
+                (8..=8, 1),
+                (9..=9, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn adjacent_assignments_do_not_overlap() {
+        let roster = Roster::parse("1-2,3-4\n5-5").unwrap();
+
+        assert_eq!(roster.depths(), [(1..=5, 1)]);
+        assert_eq!(roster.minimal_cover(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn minimal_cover() {
+        let roster = Roster::parse(ROSTER).unwrap();
+
+        assert_eq!(roster.minimal_cover(), [0, 1, 3]);
+    }
+
+    #[test]
+    fn coverage() {
+        let coverage = Roster::parse(ROSTER).unwrap().coverage(1).unwrap();
+
+        assert_eq!(coverage.sections, 1..=9);
+        assert_eq!(coverage.gaps, [6..=7]);
+        assert_eq!(coverage.crowded, [(2..=4, 2), (9..=9, 2)]);
+        assert_eq!(coverage.max_depth, 2);
+        assert_eq!(coverage.deepest, [2..=4, 9..=9]);
+        assert_eq!(coverage.redundant, [2, 4, 5]);
+    }
+
+    #[test]
+    fn nothing_assigned() {
+        let roster = Roster::parse("5-3").unwrap();
+
+        assert_eq!(
+            roster.coverage(1).err().map(|e| e.to_string()),
+            Some("no solution: no sections assigned".to_string())
+        );
+    }
+}
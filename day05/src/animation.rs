@@ -0,0 +1,97 @@
+use crate::{diagram, top_crates, Crane, MoveError, Procedure, Rearrangement, Stack};
+use aoc::step::Simulation;
+
+// a procedure being carried out one move at a time
+pub struct Rearranging<'a> {
+    procedure: &'a Procedure,
+    crane: &'a dyn Crane,
+    lenient: bool,
+    stacks: Vec<Stack>,
+    next_move: usize,
+    warnings: Vec<MoveError>,
+    error: Option<MoveError>,
+}
+
+impl<'a> Rearranging<'a> {
+    pub fn new(procedure: &'a Procedure, crane: &'a dyn Crane, lenient: bool) -> Self {
+        Self {
+            procedure,
+            crane,
+            lenient,
+            stacks: procedure.stacks.clone(),
+            next_move: 0,
+            warnings: vec![],
+            error: None,
+        }
+    }
+
+    pub fn stacks(&self) -> &[Stack] {
+        &self.stacks
+    }
+
+    // the number of moves carried out so far
+    pub fn moves_done(&self) -> usize {
+        self.next_move
+    }
+
+    // runs the next move, returns `false` once there are none left
+    pub fn advance(&mut self) -> Result<bool, MoveError> {
+        if self.next_move == self.procedure.moves.len() {
+            return Ok(false);
+        }
+
+        let prepared = self.procedure.prepare(
+            &self.stacks[..],
+            self.next_move,
+            self.lenient,
+            &mut self.warnings,
+        )?;
+        if let Some(step) = prepared {
+            self.crane.apply(&mut self.stacks, &step);
+        }
+        self.next_move += 1;
+
+        Ok(true)
+    }
+
+    pub fn finish(self) -> Rearrangement {
+        Rearrangement {
+            stacks: self.stacks,
+            warnings: self.warnings,
+        }
+    }
+}
+
+impl Simulation for Rearranging<'_> {
+    fn step(&mut self) -> bool {
+        match self.advance() {
+            Ok(more) => more,
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        diagram(&self.stacks)
+    }
+
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let next = match self.procedure.moves.get(self.next_move) {
+            Some(step) => step.to_string(),
+            None => "none".to_string(),
+        };
+
+        let mut values = vec![
+            ("moves", self.next_move.to_string()),
+            ("next", next),
+            ("top", top_crates(&self.stacks)),
+            ("warnings", self.warnings.len().to_string()),
+        ];
+        if let Some(error) = &self.error {
+            values.push(("error", error.to_string()));
+        }
+        values
+    }
+}
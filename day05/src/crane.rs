@@ -0,0 +1,102 @@
+use crate::{Move, RopeStacks, Stack};
+
+// how a crane carries out a `move N from A to B` step
+pub trait Crane {
+    fn name(&self) -> String;
+
+    // the stacks of the move are numbered from 1, like in the input
+    fn apply(&self, stacks: &mut [Stack], step: &Move);
+
+    fn apply_rope(&self, stacks: &mut RopeStacks, step: &Move);
+}
+
+// lifts the top `count` crates of a stack at once, keeping their order
+fn lift(stacks: &mut [Stack], from: usize, to: usize, count: usize) {
+    let from_len = stacks[from - 1].len();
+    let lifted = stacks[from - 1].split_off(from_len - count);
+    stacks[to - 1].extend(lifted);
+}
+
+// moves one crate at a time, part 1
+pub struct CrateMover9000;
+
+// moves all the crates at once, part 2
+pub struct CrateMover9001;
+
+// moves up to `capacity` crates at once - on rope stacks every lift is a split and a merge, so a
+// move takes count / capacity of them instead of a single one
+pub struct CappedCrane {
+    pub capacity: usize,
+}
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "9000".to_string()
+    }
+
+    fn apply(&self, stacks: &mut [Stack], step: &Move) {
+        for _ in 0..step.count {
+            let crt = stacks[step.from - 1]
+                .pop()
+                .expect("Moves are checked before they run!");
+            stacks[step.to - 1].push(crt);
+        }
+    }
+
+    fn apply_rope(&self, stacks: &mut RopeStacks, step: &Move) {
+        stacks.lift_reversed(step.from, step.to, step.count);
+    }
+}
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "9001".to_string()
+    }
+
+    fn apply(&self, stacks: &mut [Stack], step: &Move) {
+        lift(stacks, step.from, step.to, step.count);
+    }
+
+    fn apply_rope(&self, stacks: &mut RopeStacks, step: &Move) {
+        stacks.lift(step.from, step.to, step.count);
+    }
+}
+
+impl Crane for CappedCrane {
+    fn name(&self) -> String {
+        format!("capped-{}", self.capacity)
+    }
+
+    fn apply(&self, stacks: &mut [Stack], step: &Move) {
+        let mut remaining = step.count;
+        while remaining > 0 {
+            let count = remaining.min(self.capacity);
+            lift(stacks, step.from, step.to, count);
+            remaining -= count;
+        }
+    }
+
+    fn apply_rope(&self, stacks: &mut RopeStacks, step: &Move) {
+        let mut remaining = step.count;
+        while remaining > 0 {
+            let count = remaining.min(self.capacity);
+            stacks.lift(step.from, step.to, count);
+            remaining -= count;
+        }
+    }
+}
+
+// `9000`, `9001` or `capped-K` for a crane moving up to K crates at once
+pub fn crane(name: &str) -> Option<Box<dyn Crane>> {
+    match name {
+        "9000" => Some(Box::new(CrateMover9000)),
+        "9001" => Some(Box::new(CrateMover9001)),
+        _ => {
+            let capacity = name.strip_prefix("capped-")?.parse::<usize>().ok()?;
+            if capacity == 0 {
+                return None;
+            }
+            Some(Box::new(CappedCrane { capacity }))
+        }
+    }
+}
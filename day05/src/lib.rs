@@ -0,0 +1,389 @@
+use aoc::{Answer, Error, Part};
+use std::fmt;
+
+mod animation;
+mod crane;
+mod planner;
+mod rope;
+pub use animation::Rearranging;
+pub use crane::{crane, CappedCrane, Crane, CrateMover9000, CrateMover9001};
+pub use planner::plan;
+pub use rope::RopeStacks;
+
+// a crate is named by everything between its brackets - usually a single letter
+pub type Stack = Vec<String>;
+
+// (first char, last char + 1, text) of every whitespace separated token
+fn tokens(line: &str) -> Vec<(usize, usize, &str)> {
+    let chars = line.char_indices().collect::<Vec<(usize, char)>>();
+    let mut result = vec![];
+
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].1.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && !chars[i].1.is_whitespace() {
+            i += 1;
+        }
+        let (from, to) = (chars[start].0, chars.get(i).map_or(line.len(), |e| e.0));
+        result.push((start, i, &line[from..to]));
+    }
+
+    result
+}
+
+// columns are located by the positions of their labels, so the labels can have any width and
+// the rows do not need trailing whitespace - crates belong to the label closest to them
+fn parse_crates(crates_block: &[&str]) -> Result<Vec<Stack>, Error> {
+    let (label_line, rows) = match crates_block.split_last() {
+        Some(split) => split,
+        None => return Err(Error::parse(1, "missing the crates diagram")),
+    };
+    let label_number = crates_block.len();
+
+    let mut labels = vec![];
+    for (start, end, label) in tokens(label_line) {
+        let label = label
+            .parse::<usize>()
+            .map_err(|_| Error::parse(label_number, format!("invalid stack label {label}")))?;
+        labels.push((start, end, label));
+    }
+
+    // stacks are ordered by their labels, which have to be 1 to n in any order
+    let mut sorted = labels.iter().map(|e| e.2).collect::<Vec<usize>>();
+    sorted.sort_unstable();
+    if sorted.iter().enumerate().any(|(i, &label)| label != i + 1) {
+        return Err(Error::parse(
+            label_number,
+            "stack labels have to be numbered from 1 without gaps",
+        ));
+    }
+
+    let mut stacks = vec![Stack::new(); labels.len()];
+    for (i, row) in rows.iter().enumerate().rev() {
+        let mut filled = vec![false; labels.len()];
+
+        for (start, end, token) in tokens(row) {
+            let name = match token.strip_prefix('[').and_then(|e| e.strip_suffix(']')) {
+                Some(name) if !name.is_empty() => name,
+                _ => return Err(Error::parse(i + 1, format!("invalid crate {token}"))),
+            };
+
+            // doubled, so the centers stay integers
+            let center = start + end;
+            let column = (0..labels.len())
+                .min_by_key(|&e| (labels[e].0 + labels[e].1).abs_diff(center))
+                .ok_or_else(|| Error::parse(i + 1, "crates without stacks"))?;
+
+            if filled[column] {
+                return Err(Error::parse(
+                    i + 1,
+                    format!("two crates in stack {}", labels[column].2),
+                ));
+            }
+            filled[column] = true;
+            stacks[labels[column].2 - 1].push(name.to_string());
+        }
+    }
+
+    Ok(stacks)
+}
+
+// a single `move N from A to B` step, stacks are numbered from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+// what moves are checked against, one stack at a time - stacks are numbered from 1
+trait Heights {
+    fn stack_count(&self) -> usize;
+    fn height(&self, stack: usize) -> usize;
+}
+
+impl Heights for [Stack] {
+    fn stack_count(&self) -> usize {
+        self.len()
+    }
+
+    fn height(&self, stack: usize) -> usize {
+        self[stack - 1].len()
+    }
+}
+
+impl Heights for RopeStacks {
+    fn stack_count(&self) -> usize {
+        self.stack_count()
+    }
+
+    fn height(&self, stack: usize) -> usize {
+        self.height(stack)
+    }
+}
+
+// the starting stacks (bottom to top) and the rearrangement procedure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    pub stacks: Vec<Stack>,
+    pub moves: Vec<Move>,
+    // the input line of the first move, the others follow one per line
+    pub first_move_line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveProblem {
+    UnknownStack(usize),
+    // more crates than the stack holds
+    NotEnoughCrates,
+}
+
+// a move that cannot be carried out in the state the stacks are in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    pub line: usize,
+    pub step: Move,
+    pub problem: MoveProblem,
+    // the stacks' heights before the move
+    pub heights: Vec<usize>,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let heights = self
+            .heights
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>();
+
+        match self.problem {
+            MoveProblem::UnknownStack(stack) => write!(f, "{} - no stack {stack}", self.step)?,
+            MoveProblem::NotEnoughCrates => write!(
+                f,
+                "{} - stack {} has only {} crates",
+                self.step,
+                self.step.from,
+                self.heights[self.step.from - 1]
+            )?,
+        }
+        write!(f, " (heights {})", heights.join(" "))
+    }
+}
+
+impl From<MoveError> for Error {
+    fn from(error: MoveError) -> Self {
+        Error::parse(error.line, error.to_string())
+    }
+}
+
+// the final stacks and the moves that had to be adjusted to run
+pub struct Rearrangement {
+    pub stacks: Vec<Stack>,
+    pub warnings: Vec<MoveError>,
+}
+
+impl Procedure {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut lines = input.lines();
+
+        let crates_block = lines
+            .by_ref()
+            .take_while(|&e| !e.is_empty())
+            .collect::<Vec<&str>>();
+
+        let stacks = parse_crates(&crates_block)?;
+        let mut moves = vec![];
+
+        for (i, line) in lines.enumerate() {
+            let line_number = crates_block.len() + 2 + i;
+
+            match line.split_whitespace().collect::<Vec<&str>>()[..] {
+                ["move", count_str, "from", from_str, "to", to_str] => {
+                    let maybe_usize: Vec<Result<usize, _>> = [count_str, from_str, to_str]
+                        .into_iter()
+                        .map(|e| e.parse::<usize>())
+                        .collect();
+
+                    match maybe_usize[..] {
+                        [Ok(count), Ok(from), Ok(to)] => moves.push(Move { count, from, to }),
+                        _ => return Err(Error::parse(line_number, format!("invalid move {line}"))),
+                    }
+                }
+                _ => {
+                    return Err(Error::parse(
+                        line_number,
+                        format!("unknown instruction {line}"),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            stacks,
+            moves,
+            first_move_line: crates_block.len() + 2,
+        })
+    }
+
+    fn check(&self, stacks: &(impl Heights + ?Sized), i: usize) -> Result<(), MoveError> {
+        let step = &self.moves[i];
+        let count = stacks.stack_count();
+
+        let problem =
+            if let Some(&stack) = [step.from, step.to].iter().find(|&&e| e == 0 || e > count) {
+                MoveProblem::UnknownStack(stack)
+            } else if stacks.height(step.from) < step.count {
+                MoveProblem::NotEnoughCrates
+            } else {
+                return Ok(());
+            };
+
+        Err(MoveError {
+            line: self.first_move_line + i,
+            step: step.clone(),
+            problem,
+            // only collected for the report
+            heights: (1..=count).map(|e| stacks.height(e)).collect(),
+        })
+    }
+
+    // the `i`th move as it has to be carried out - `None` when lenient skips it
+    fn prepare(
+        &self,
+        stacks: &(impl Heights + ?Sized),
+        i: usize,
+        lenient: bool,
+        warnings: &mut Vec<MoveError>,
+    ) -> Result<Option<Move>, MoveError> {
+        let step = &self.moves[i];
+
+        match self.check(stacks, i) {
+            Ok(()) => Ok(Some(step.clone())),
+            Err(error) if lenient => {
+                let clamped = (error.problem == MoveProblem::NotEnoughCrates).then(|| Move {
+                    count: stacks.height(step.from),
+                    ..step.clone()
+                });
+                warnings.push(error);
+                Ok(clamped)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    // validates every move before it runs - the first invalid one is an error, unless lenient,
+    // which skips moves referencing unknown stacks and moves only the crates there are
+    pub fn run(&self, crane: &dyn Crane, lenient: bool) -> Result<Rearrangement, MoveError> {
+        let mut rearranging = Rearranging::new(self, crane, lenient);
+        while rearranging.advance()? {}
+
+        Ok(rearranging.finish())
+    }
+
+    // `run` on rope stacks, which move any number of crates in logarithmic time
+    pub fn run_rope(&self, crane: &dyn Crane, lenient: bool) -> Result<Rearrangement, MoveError> {
+        let mut stacks = RopeStacks::new(&self.stacks);
+        let mut warnings = vec![];
+
+        for i in 0..self.moves.len() {
+            if let Some(step) = self.prepare(&stacks, i, lenient, &mut warnings)? {
+                crane.apply_rope(&mut stacks, &step);
+            }
+        }
+
+        Ok(Rearrangement {
+            stacks: stacks.to_stacks(),
+            warnings,
+        })
+    }
+}
+
+// the stacks in the puzzle's diagram format - every line is padded to the full width, columns are
+// as wide as their longest crate or label
+pub fn diagram(stacks: &[Stack]) -> String {
+    let height = stacks.iter().map(|e| e.len()).max().unwrap_or(0);
+    let widths = stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| {
+            let widest = stack.iter().map(|e| e.chars().count()).max().unwrap_or(1);
+            widest.max((i + 1).to_string().len()) + 2
+        })
+        .collect::<Vec<usize>>();
+
+    let mut result = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .zip(&widths)
+            .map(|(stack, &width)| match stack.get(level) {
+                Some(crt) => format!("{:<width$}", format!("[{crt}]")),
+                None => " ".repeat(width),
+            })
+            .collect::<Vec<String>>();
+        result.push_str(&row.join(" "));
+        result.push('\n');
+    }
+
+    let numbers = widths
+        .iter()
+        .enumerate()
+        .map(|(i, &width)| format!(" {:<label$} ", i + 1, label = width - 2))
+        .collect::<Vec<String>>();
+    result.push_str(&numbers.join(" "));
+    result.push('\n');
+
+    result
+}
+
+// writes the puzzle input back
+impl fmt::Display for Procedure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", diagram(&self.stacks))?;
+
+        for step in &self.moves {
+            writeln!(f, "{step}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// runs the whole procedure and returns the final stacks
+pub fn rearrange(input: &str, part: Part) -> Result<Vec<Stack>, Error> {
+    match part {
+        Part::One => rearrange_with(input, &CrateMover9000),
+        Part::Two => rearrange_with(input, &CrateMover9001),
+    }
+}
+
+pub fn rearrange_with(input: &str, crane: &dyn Crane) -> Result<Vec<Stack>, Error> {
+    let rearrangement = Procedure::parse(input)?.run(crane, false)?;
+
+    Ok(rearrangement.stacks)
+}
+
+// the crates on top of the stacks, a space for an empty one
+pub fn top_crates(stacks: &[Stack]) -> String {
+    stacks
+        .iter()
+        .map(|e| e.last().map_or(" ", |e| e.as_str()))
+        .collect::<String>()
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let crates = rearrange(input, part)?;
+
+    Ok(top_crates(&crates).into())
+}
@@ -1,86 +1,202 @@
+use aoc::params::Params;
+use aoc::step;
+use aoc::{Error, Part};
+use day05::{Crane, CrateMover9000, CrateMover9001, MoveError, Procedure, Rearranging};
 use std::env;
 use std::fs;
+use std::io::{self, Write};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// shows the stacks while the procedure runs
+trait Renderer {
+    fn frame(&mut self, moves: usize, diagram: &str);
+}
+
+// prints every frame below the previous one
+struct Log;
+
+impl Renderer for Log {
+    fn frame(&mut self, moves: usize, diagram: &str) {
+        println!("after {moves} moves:");
+        println!("{diagram}");
+    }
+}
+
+// redraws the frames in place, an animation in a terminal understanding ANSI escapes
+struct Terminal {
+    delay: Duration,
+}
+
+impl Renderer for Terminal {
+    fn frame(&mut self, moves: usize, diagram: &str) {
+        // clears the screen and moves the cursor to the top left corner
+        print!("\x1b[2J\x1b[H");
+        println!("after {moves} moves:");
+        println!("{diagram}");
+        io::stdout().flush().expect("Could not draw the frame!");
+        thread::sleep(self.delay);
+    }
+}
+
+// runs the procedure rendering the diagram every `frames` moves, 0 for none
+fn animate(
+    rearranging: &mut Rearranging,
+    frames: usize,
+    renderer: &mut dyn Renderer,
+) -> Result<(), MoveError> {
+    while rearranging.advance()? {
+        if frames > 0 && rearranging.moves_done().is_multiple_of(frames) {
+            renderer.frame(
+                rearranging.moves_done(),
+                &day05::diagram(rearranging.stacks()),
+            );
+        }
+    }
+
+    Ok(())
+}
 
 fn main() {
-    let args = env::args().collect::<Vec<String>>();
+    let mut params = Params::from_args(env::args()).unwrap_or_else(|e| panic!("{e}"));
+    // `9000`, `9001` or `capped-K` - only that crane is run instead of both parts
+    let crane_name: String = params
+        .take("crane", String::new())
+        .unwrap_or_else(|e| panic!("{e}"));
+    // clamps moves to the crates there are and skips unknown stacks instead of failing
+    let lenient = params.flag("lenient").unwrap_or_else(|e| panic!("{e}"));
+    // prints the stacks after every N moves
+    let frames: usize = params.take("frames", 0).unwrap_or_else(|e| panic!("{e}"));
+    // redraws the frames in place, after every move unless `--frames` is given
+    let animated = params.flag("animate").unwrap_or_else(|e| panic!("{e}"));
+    // milliseconds between the frames of the animation
+    let delay: u64 = params.take("delay", 100).unwrap_or_else(|e| panic!("{e}"));
+    // steps through the moves of the first crane - part 1 unless `--crane` is given, the planner
+    // uses the same crane
+    let step_mode = params.flag("step").unwrap_or_else(|e| panic!("{e}"));
+    // a file with the diagram to reach - prints a plan from the input's diagram instead
+    let target_path: String = params
+        .take("plan", String::new())
+        .unwrap_or_else(|e| panic!("{e}"));
+    // the number of states the planner may visit
+    let limit: usize = params
+        .take("limit", 10_000)
+        .unwrap_or_else(|e| panic!("{e}"));
+    // runs on rope stacks, for huge procedures - no frames then
+    let rope = params.flag("rope").unwrap_or_else(|e| panic!("{e}"));
+    // times the plain and the rope stacks instead of printing the answers
+    let benchmark = params.flag("benchmark").unwrap_or_else(|e| panic!("{e}"));
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
+
     let file_path = args
         .get(1)
         .expect("Pass in input file path as the first argument!");
 
     let content = fs::read_to_string(file_path).expect("Could not read the input file!");
 
-    let mut lines = content.lines();
+    let procedure = match Procedure::parse(&content) {
+        Ok(procedure) => procedure,
+        Err(e) => {
+            for part in [Part::One, Part::Two] {
+                eprintln!("{part}: {e}");
+            }
+            process::exit(1);
+        }
+    };
 
-    let crates_block = lines
-        .by_ref()
-        .take_while(|&e| e != "")
-        .collect::<Vec<&str>>();
+    let cranes: Vec<(String, Box<dyn Crane>)> = if crane_name.is_empty() {
+        vec![
+            (Part::One.to_string(), Box::new(CrateMover9000)),
+            (Part::Two.to_string(), Box::new(CrateMover9001)),
+        ]
+    } else {
+        let crane = day05::crane(&crane_name).unwrap_or_else(|| {
+            panic!("Unknown crane {crane_name}, expected `9000`, `9001` or `capped-K`!")
+        });
+        vec![(crane.name(), crane)]
+    };
 
-    let crates_block_reversed = crates_block.iter().rev().collect::<Vec<&&str>>();
+    if !target_path.is_empty() {
+        let target = fs::read_to_string(&target_path).expect("Could not read the target file!");
+        let crane = cranes[0].1.as_ref();
 
-    let mut crates: Vec<Vec<char>> = Vec::new();
+        match Procedure::parse(&target)
+            .and_then(|target| day05::plan(&procedure.stacks, &target.stacks, crane, limit))
+        {
+            Ok(plan) => {
+                print!("{plan}");
+                println!("{} moves for crane {}", plan.moves.len(), crane.name());
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+        return;
+    }
 
-    for i in 0..crates_block_reversed[0].len() {
-        let line = (0..crates_block_reversed.len()).fold(String::new(), |mut a, e| {
-            a.push(crates_block_reversed[e].chars().nth(i).unwrap());
-            a
-        });
+    if step_mode {
+        let mut rearranging = Rearranging::new(&procedure, cranes[0].1.as_ref(), lenient);
+        step::run(&mut rearranging, io::stdin().lock(), io::stdout().lock())
+            .expect("Could not run the step mode!");
+        return;
+    }
 
-        let first_char = line.chars().nth(0).unwrap();
-        if let Some(column) = first_char.to_string().parse::<usize>().ok() {
-            // using parsed column to index crates is bad
-            crates.push(Vec::new());
+    if benchmark {
+        for (label, crane) in &cranes {
+            let start = Instant::now();
+            let plain = procedure.run(crane.as_ref(), lenient);
+            let plain_time = start.elapsed();
 
-            line.chars().skip(1).filter(|&e| e != ' ').for_each(|e| {
-                let _ = &crates[column - 1].push(e);
-                ()
-            });
+            let start = Instant::now();
+            let roped = procedure.run_rope(crane.as_ref(), lenient);
+            let rope_time = start.elapsed();
+
+            let same = match (&plain, &roped) {
+                (Ok(plain), Ok(roped)) => plain.stacks == roped.stacks,
+                (Err(plain), Err(roped)) => plain == roped,
+                _ => false,
+            };
+            println!(
+                "{label}: vectors {plain_time:?}, rope {rope_time:?}{}",
+                if same { "" } else { " - the results differ!" }
+            );
         }
+        return;
     }
 
-    for line in lines {
-        match line.split_whitespace().collect::<Vec<&str>>()[..] {
-            ["move", count_str, "from", from_str, "to", to_str] => {
-                let maybe_usize: Vec<Result<usize, _>> = vec![count_str, from_str, to_str]
-                    .into_iter()
-                    .map(|e| e.parse::<usize>())
-                    .collect();
-
-                match maybe_usize[..] {
-                    [Ok(count), Ok(from), Ok(to)] => {
-                        // part one ->
-                        // for _ in 0..count {
-                        //     let moved_char = crates[from - 1].pop().unwrap();
-                        //     crates[to - 1].push(moved_char);
-                        // }
-                        // part one <-
-
-                        // part two ->
-                        let removed_chars: Vec<char> = {
-                            let from_len = &crates[from - 1].len();
-                            let result = crates[from - 1]
-                                .drain((from_len - count)..)
-                                .into_iter()
-                                .collect::<Vec<char>>();
-                            result
-                        };
-                        let _ = &crates[to - 1].extend(removed_chars);
-                        // part two <-
-                    }
-                    _ => unimplemented!(),
-                }
+    let (frames, mut renderer): (usize, Box<dyn Renderer>) = if animated {
+        let delay = Duration::from_millis(delay);
+        (frames.max(1), Box::new(Terminal { delay }))
+    } else {
+        (frames, Box::new(Log))
+    };
+
+    let mut failed = false;
+    for (label, crane) in cranes {
+        let rearrangement = if rope {
+            procedure.run_rope(crane.as_ref(), lenient)
+        } else {
+            let mut rearranging = Rearranging::new(&procedure, crane.as_ref(), lenient);
+            animate(&mut rearranging, frames, renderer.as_mut()).map(|_| rearranging.finish())
+        };
+        let rearrangement = match rearrangement {
+            Ok(rearrangement) => rearrangement,
+            Err(e) => {
+                eprintln!("{label}: {}", Error::from(e));
+                failed = true;
+                continue;
             }
-            _ => unimplemented!(),
+        };
+
+        for warning in &rearrangement.warnings {
+            println!("warning on line {}: {warning}", warning.line);
         }
+        print!("{}", day05::diagram(&rearrangement.stacks));
+        println!("{label}: {}", day05::top_crates(&rearrangement.stacks));
     }
-
-    for (i, crt) in crates.iter().enumerate() {
-        println!("stack #{}: {:?}", i, crt);
+    if failed {
+        process::exit(1);
     }
-
-    print!("result: ");
-    crates
-        .iter()
-        .for_each(|e| print!("{}", e.last().unwrap_or(&' ')));
-    println!("");
 }
@@ -0,0 +1,141 @@
+use crate::{Crane, Move, Procedure, Stack};
+use aoc::Error;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::rc::Rc;
+
+// the bottom crates of a stack that are already where the target wants them
+fn settled(stack: &Stack, target: &Stack) -> usize {
+    stack.iter().zip(target).take_while(|(a, b)| a == b).count()
+}
+
+// a move takes crates off one stack and puts them on another, so it can fix at most one stack
+// with crates to remove and one with crates to receive
+fn moves_needed(stacks: &[Stack], target: &[Stack]) -> usize {
+    let (mut to_remove, mut to_receive) = (0, 0);
+    for (stack, target) in stacks.iter().zip(target) {
+        let settled = settled(stack, target);
+        if stack.len() > settled {
+            to_remove += 1;
+        }
+        if target.len() > settled {
+            to_receive += 1;
+        }
+    }
+    to_remove.max(to_receive)
+}
+
+// a state, the state it was reached from and the move
+type Visited = (Rc<[Stack]>, Option<(usize, Move)>);
+
+// the states kept in memory whatever the limit, a visit queues a state for nearly every move
+// so with many stacks they pile up far faster than states are visited
+const MAX_STATES: usize = 100_000;
+
+fn sorted_crates(stacks: &[Stack]) -> Vec<&String> {
+    let mut crates = stacks.iter().flatten().collect::<Vec<&String>>();
+    crates.sort_unstable();
+    crates
+}
+
+// the fewest moves turning the start into the target for the crane - an A* search over the
+// stack states, giving up after visiting `limit` states or keeping `MAX_STATES` of them
+//
+// the plan is verified by running it and returned as a procedure ready to be written out
+pub fn plan(
+    start: &[Stack],
+    target: &[Stack],
+    crane: &dyn Crane,
+    limit: usize,
+) -> Result<Procedure, Error> {
+    if start.len() != target.len() {
+        return Err(Error::unsolvable(format!(
+            "{} stacks cannot become {}",
+            start.len(),
+            target.len()
+        )));
+    }
+    if sorted_crates(start) != sorted_crates(target) {
+        return Err(Error::unsolvable("the target has different crates"));
+    }
+
+    let start_state: Rc<[Stack]> = start.into();
+    let mut states: Vec<Visited> = vec![(start_state.clone(), None)];
+    let mut best = HashMap::from([(start_state, 0)]);
+    let mut queue = BinaryHeap::from([Reverse((moves_needed(start, target), 0, 0))]);
+    // states taken off the queue, the ones only pushed onto it are not counted
+    let mut visited = 0;
+
+    let found = loop {
+        let Reverse((_, moves, id)) = match queue.pop() {
+            Some(entry) => entry,
+            None => return Err(Error::unsolvable("the target cannot be reached")),
+        };
+        if best[&states[id].0] < moves {
+            continue;
+        }
+        visited += 1;
+        if visited > limit {
+            return Err(Error::unsolvable(format!(
+                "no plan found within {limit} states"
+            )));
+        }
+        if *states[id].0 == *target {
+            break id;
+        }
+
+        let stacks = states[id].0.to_vec();
+        for from in 0..stacks.len() {
+            for to in (0..stacks.len()).filter(|&e| e != from) {
+                for count in 1..=stacks[from].len() {
+                    let step = Move {
+                        count,
+                        from: from + 1,
+                        to: to + 1,
+                    };
+                    let mut next = stacks.clone();
+                    crane.apply(&mut next, &step);
+
+                    if best.get(&next[..]).is_some_and(|&e| e <= moves + 1) {
+                        continue;
+                    }
+                    if states.len() == MAX_STATES {
+                        return Err(Error::unsolvable(format!(
+                            "no plan found within {MAX_STATES} states kept in memory"
+                        )));
+                    }
+
+                    let next: Rc<[Stack]> = next.into();
+                    best.insert(next.clone(), moves + 1);
+                    queue.push(Reverse((
+                        moves + 1 + moves_needed(&next, target),
+                        moves + 1,
+                        states.len(),
+                    )));
+                    states.push((next, Some((id, step))));
+                }
+            }
+        }
+    };
+
+    let mut moves = vec![];
+    let mut id = found;
+    while let Some((previous, step)) = &states[id].1 {
+        moves.push(step.clone());
+        id = *previous;
+    }
+    moves.reverse();
+
+    let procedure = Procedure {
+        stacks: start.to_vec(),
+        moves,
+        // below the diagram rows, the labels and the blank line
+        first_move_line: start.iter().map(|e| e.len()).max().unwrap_or(0) + 3,
+    };
+    let result = procedure.run(crane, false)?;
+    if result.stacks != target {
+        return Err(Error::unsolvable("the plan does not reach the target"));
+    }
+
+    Ok(procedure)
+}
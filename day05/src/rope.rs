@@ -0,0 +1,217 @@
+use crate::Stack;
+use std::collections::HashMap;
+
+// the empty tree, node 0 is never used
+const NIL: u32 = 0;
+
+#[derive(Debug, Clone)]
+struct Node {
+    crt: u32,
+    priority: u64,
+    size: u32,
+    left: u32,
+    right: u32,
+    // the subtree has to be read in reverse, applied lazily on the way down
+    reversed: bool,
+}
+
+// every stack is an implicit treap of its crates, bottom to top - a move splits off the top of
+// one tree and merges it onto another, both in O(log n) whatever the number of crates moved,
+// and moving crates one at a time only flips the reversed flag of the moved tree
+pub struct RopeStacks {
+    // crate names are interned, the nodes hold their indices
+    names: Vec<String>,
+    nodes: Vec<Node>,
+    roots: Vec<u32>,
+    seed: u64,
+}
+
+impl RopeStacks {
+    pub fn new(stacks: &[Stack]) -> Self {
+        let mut rope = Self {
+            names: vec![],
+            nodes: vec![Node {
+                crt: 0,
+                priority: 0,
+                size: 0,
+                left: NIL,
+                right: NIL,
+                reversed: false,
+            }],
+            roots: vec![NIL; stacks.len()],
+            seed: 1,
+        };
+
+        let mut ids = HashMap::new();
+        for (i, stack) in stacks.iter().enumerate() {
+            for name in stack {
+                let crt = *ids.entry(name).or_insert_with(|| {
+                    rope.names.push(name.clone());
+                    rope.names.len() as u32 - 1
+                });
+                let node = rope.new_node(crt);
+                rope.roots[i] = rope.merge(rope.roots[i], node);
+            }
+        }
+
+        rope
+    }
+
+    // splitmix64, the priorities only have to look random
+    fn next_priority(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn new_node(&mut self, crt: u32) -> u32 {
+        let priority = self.next_priority();
+        self.nodes.push(Node {
+            crt,
+            priority,
+            size: 1,
+            left: NIL,
+            right: NIL,
+            reversed: false,
+        });
+        self.nodes.len() as u32 - 1
+    }
+
+    fn size(&self, tree: u32) -> u32 {
+        self.nodes[tree as usize].size
+    }
+
+    fn reverse(&mut self, tree: u32) {
+        if tree != NIL {
+            let node = &mut self.nodes[tree as usize];
+            node.reversed = !node.reversed;
+        }
+    }
+
+    fn push_down(&mut self, tree: u32) {
+        let node = &mut self.nodes[tree as usize];
+        if node.reversed {
+            node.reversed = false;
+            std::mem::swap(&mut node.left, &mut node.right);
+            let (left, right) = (node.left, node.right);
+            self.reverse(left);
+            self.reverse(right);
+        }
+    }
+
+    fn update(&mut self, tree: u32) {
+        let node = &self.nodes[tree as usize];
+        let size = 1 + self.size(node.left) + self.size(node.right);
+        self.nodes[tree as usize].size = size;
+    }
+
+    // the first `count` crates and the rest
+    fn split(&mut self, tree: u32, count: u32) -> (u32, u32) {
+        if tree == NIL {
+            return (NIL, NIL);
+        }
+        self.push_down(tree);
+
+        let left = self.nodes[tree as usize].left;
+        if self.size(left) >= count {
+            let (first, rest) = self.split(left, count);
+            self.nodes[tree as usize].left = rest;
+            self.update(tree);
+            (first, tree)
+        } else {
+            let right = self.nodes[tree as usize].right;
+            let (first, rest) = self.split(right, count - self.size(left) - 1);
+            self.nodes[tree as usize].right = first;
+            self.update(tree);
+            (tree, rest)
+        }
+    }
+
+    fn merge(&mut self, first: u32, second: u32) -> u32 {
+        if first == NIL {
+            return second;
+        }
+        if second == NIL {
+            return first;
+        }
+
+        if self.nodes[first as usize].priority > self.nodes[second as usize].priority {
+            self.push_down(first);
+            let right = self.nodes[first as usize].right;
+            self.nodes[first as usize].right = self.merge(right, second);
+            self.update(first);
+            first
+        } else {
+            self.push_down(second);
+            let left = self.nodes[second as usize].left;
+            self.nodes[second as usize].left = self.merge(first, left);
+            self.update(second);
+            second
+        }
+    }
+
+    pub fn stack_count(&self) -> usize {
+        self.roots.len()
+    }
+
+    // stacks are numbered from 1
+    pub fn height(&self, stack: usize) -> usize {
+        self.size(self.roots[stack - 1]) as usize
+    }
+
+    // the top `count` crates of a stack - stacks are numbered from 1
+    fn take_top(&mut self, from: usize, count: usize) -> u32 {
+        let root = self.roots[from - 1];
+        let (rest, top) = self.split(root, self.size(root) - count as u32);
+        self.roots[from - 1] = rest;
+        top
+    }
+
+    // moves the crates at once, keeping their order
+    pub fn lift(&mut self, from: usize, to: usize, count: usize) {
+        let top = self.take_top(from, count);
+        self.roots[to - 1] = self.merge(self.roots[to - 1], top);
+    }
+
+    // moves the crates one at a time, reversing their order
+    pub fn lift_reversed(&mut self, from: usize, to: usize, count: usize) {
+        // every crate lands back where it was lifted from
+        if from == to {
+            return;
+        }
+
+        let top = self.take_top(from, count);
+        self.reverse(top);
+        self.roots[to - 1] = self.merge(self.roots[to - 1], top);
+    }
+
+    pub fn to_stacks(&mut self) -> Vec<Stack> {
+        let mut result = vec![];
+
+        for i in 0..self.roots.len() {
+            let mut stack = Stack::new();
+
+            // in order, pushing the reversed flags down on the way
+            let mut pending = vec![];
+            let mut tree = self.roots[i];
+            while tree != NIL || !pending.is_empty() {
+                while tree != NIL {
+                    self.push_down(tree);
+                    pending.push(tree);
+                    tree = self.nodes[tree as usize].left;
+                }
+                let node = pending
+                    .pop()
+                    .expect("The loop only runs with pending nodes!");
+                stack.push(self.names[self.nodes[node as usize].crt as usize].clone());
+                tree = self.nodes[node as usize].right;
+            }
+
+            result.push(stack);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,114 @@
+use day05::{crane, plan, Procedure};
+
+const SAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+// the sample diagram after its first two moves
+const TWO_MOVES: &str = "        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3 
+";
+
+// every crate in reverse order of the sample
+const REVERSED: &str = "[P] [M] [C]
+[N] [D] [Z]
+ 1   2   3 
+";
+
+const TWO_STACKS: &str = "[N] [C]
+[Z] [D]
+ 1   2 
+";
+
+// 21 crates shuffled over 7 stacks, far too many states to search them all
+const SHUFFLED: &str = "[F]             [D]
+[A] [K]         [P]
+[R] [B]         [S]
+[O] [J]     [E] [G] [U] [C]
+[H] [L] [M] [I] [Q] [N] [T]
+ 1   2   3   4   5   6   7 
+";
+
+const RESHUFFLED: &str = "        [S]
+        [T] [R]
+    [K] [E] [J] [F]     [P]
+    [B] [A] [G] [U] [D] [I]
+[O] [N] [C] [L] [H] [Q] [M]
+ 1   2   3   4   5   6   7 
+";
+
+fn stacks(diagram: &str) -> Vec<Vec<String>> {
+    Procedure::parse(diagram).unwrap().stacks
+}
+
+#[test]
+fn plans_the_fewest_moves() {
+    let crane = crane("9000").unwrap();
+    let plan = plan(&stacks(SAMPLE), &stacks(TWO_MOVES), crane.as_ref(), 100).unwrap();
+
+    assert_eq!(plan.moves.len(), 2);
+}
+
+// the limit counts the states taken off the queue, not every state pushed onto it
+#[test]
+fn limit_counts_visited_states() {
+    let crane = crane("9000").unwrap();
+    let (start, target) = (stacks(SAMPLE), stacks(TWO_MOVES));
+
+    assert!(plan(&start, &target, crane.as_ref(), 5).is_ok());
+    assert_eq!(
+        plan(&start, &target, crane.as_ref(), 4)
+            .unwrap_err()
+            .to_string(),
+        "no solution: no plan found within 4 states"
+    );
+}
+
+#[test]
+fn limit_exceeded() {
+    let crane = crane("9001").unwrap();
+    let result = plan(&stacks(SAMPLE), &stacks(REVERSED), crane.as_ref(), 100);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "no solution: no plan found within 100 states"
+    );
+}
+
+#[test]
+fn stack_count_mismatch() {
+    let crane = crane("9000").unwrap();
+    let result = plan(&stacks(SAMPLE), &stacks(TWO_STACKS), crane.as_ref(), 100);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "no solution: 3 stacks cannot become 2"
+    );
+}
+
+// every visit queues hundreds of states here, the search has to stop before running out of memory
+#[test]
+fn large_search_fails_cleanly() {
+    let crane = crane("9000").unwrap();
+    let result = plan(
+        &stacks(SHUFFLED),
+        &stacks(RESHUFFLED),
+        crane.as_ref(),
+        usize::MAX,
+    );
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "no solution: no plan found within 100000 states kept in memory"
+    );
+}
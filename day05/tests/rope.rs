@@ -0,0 +1,61 @@
+use day05::{crane, Procedure};
+
+const SAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+// the rope stacks have to end up exactly like the plain ones for every crane
+fn assert_same(input: &str) {
+    let procedure = Procedure::parse(input).unwrap();
+
+    for name in ["9000", "9001", "capped-1", "capped-2", "capped-3"] {
+        let crane = crane(name).unwrap();
+        let plain = procedure.run(crane.as_ref(), false).unwrap();
+        let rope = procedure.run_rope(crane.as_ref(), false).unwrap();
+
+        assert_eq!(plain.stacks, rope.stacks, "crane {name}");
+    }
+}
+
+#[test]
+fn sample() {
+    assert_same(SAMPLE);
+}
+
+#[test]
+fn same_source_and_target() {
+    assert_same(&format!("{SAMPLE}move 3 from 3 to 3\nmove 1 from 1 to 1\n"));
+}
+
+#[test]
+fn whole_stack() {
+    // stack 3 holds all four crates after the sample
+    assert_same(&format!(
+        "{SAMPLE}move 4 from 3 to 1\nmove 5 from 1 to 2\nmove 5 from 2 to 3\n"
+    ));
+}
+
+#[test]
+fn many_moves() {
+    let mut input =
+        String::from("[A] [E] [I]\n[B] [F] [J]\n[C] [G] [K]\n[D] [H] [L]\n 1   2   3 \n\n");
+    let mut heights = [4, 4, 4];
+    // deterministic, but touching every kind of move
+    for i in 0..300 {
+        let from = i % 3;
+        let to = (i * 7 / 3) % 3;
+        let count = (i * 5) % (heights[from] + 1);
+        heights[from] -= count;
+        heights[to] += count;
+        input.push_str(&format!("move {count} from {} to {}\n", from + 1, to + 1));
+    }
+
+    assert_same(&input);
+}
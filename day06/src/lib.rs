@@ -0,0 +1,126 @@
+use aoc::{Answer, Error, Part};
+use std::collections::HashMap;
+
+mod stream;
+pub use stream::{scan_stream, Marker};
+
+// a sliding window over a datastream - remembers where every character was last seen, so each
+// character is handled in constant time whatever the marker length
+pub struct Detector {
+    length: usize,
+    // characters seen so far
+    position: usize,
+    // the position of the latest character repeated within the window, the characters after it
+    // are all different
+    repeated: usize,
+    last_seen: HashMap<char, usize>,
+}
+
+impl Detector {
+    pub fn new(length: usize) -> Self {
+        Self {
+            length,
+            position: 0,
+            repeated: 0,
+            last_seen: HashMap::new(),
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    // the number of different characters in a row ending at the current position
+    pub fn distinct(&self) -> usize {
+        self.position - self.repeated
+    }
+
+    // feeds the next character, returns true when it ends `length` different characters
+    pub fn push(&mut self, value: char) -> bool {
+        self.position += 1;
+        if let Some(seen) = self.last_seen.insert(value, self.position) {
+            self.repeated = self.repeated.max(seen);
+        }
+
+        self.distinct() >= self.length
+    }
+}
+
+// the datastream is a single line, the line ending after it is not part of it
+fn datastream(content: &str) -> &str {
+    content.trim_end_matches(['\n', '\r'])
+}
+
+// the positions where windows of `length` different characters end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Markers {
+    pub length: usize,
+    pub first: Option<usize>,
+    pub count: usize,
+    // every position, only collected when asked for
+    pub positions: Vec<usize>,
+}
+
+fn record(markers: &mut [Markers], distinct: usize, position: usize, all_positions: bool) {
+    for markers in markers.iter_mut().filter(|e| e.length <= distinct) {
+        markers.first.get_or_insert(position);
+        markers.count += 1;
+        if all_positions {
+            markers.positions.push(position);
+        }
+    }
+}
+
+// finds the markers of all the lengths in a single pass - a window ends at a position when the
+// characters in a row before it are all different, for every length up to their number
+pub fn scan(content: &str, lengths: &[usize], all_positions: bool) -> Vec<Markers> {
+    let mut result = lengths
+        .iter()
+        .map(|&length| Markers {
+            length,
+            first: None,
+            count: 0,
+            positions: vec![],
+        })
+        .collect::<Vec<Markers>>();
+
+    // an empty window ends before the first character too
+    record(&mut result, 0, 0, all_positions);
+
+    let mut detector = Detector::new(0);
+    for value in datastream(content).chars() {
+        detector.push(value);
+        record(
+            &mut result,
+            detector.distinct(),
+            detector.position(),
+            all_positions,
+        );
+    }
+
+    result
+}
+
+// position right after the first `sequence_length` unique characters
+pub fn find_marker(content: &str, sequence_length: usize) -> Option<usize> {
+    if sequence_length == 0 {
+        return Some(0);
+    }
+
+    let mut detector = Detector::new(sequence_length);
+    datastream(content)
+        .chars()
+        .find(|&e| detector.push(e))
+        .map(|_| detector.position())
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let sequence_length = match part {
+        Part::One => 4,
+        Part::Two => 14,
+    };
+
+    find_marker(input, sequence_length)
+        .map(|e| e.into())
+        .ok_or_else(|| Error::unsolvable(format!("no {sequence_length} unique characters found")))
+}
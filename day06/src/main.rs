@@ -1,55 +1,115 @@
+use aoc::params::Params;
+use aoc::Part;
 use std::env;
-use std::fs;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::process;
 
-fn are_unique(values: &[char], count: usize) -> bool {
-    if count < 2 {
-        return true;
-    }
+fn main() {
+    let mut params = Params::from_args(env::args()).unwrap_or_else(|e| panic!("{e}"));
+    // space separated marker lengths, e.g. `4 14`, found in a single pass instead of the parts
+    let lengths: String = params
+        .take("lengths", String::new())
+        .unwrap_or_else(|e| panic!("{e}"));
+    // lists every position where a window of the lengths ends
+    let all_positions = params.flag("all").unwrap_or_else(|e| panic!("{e}"));
+    // reads the input (`-` or none for stdin) in chunks and prints markers as they are found
+    let stream = params.flag("stream").unwrap_or_else(|e| panic!("{e}"));
+    let chunk_size: usize = params.take("chunk", 4096).unwrap_or_else(|e| panic!("{e}"));
+    // keeps streaming after the first markers, reporting every one
+    let every = params.flag("every").unwrap_or_else(|e| panic!("{e}"));
+    let args = params.finish().unwrap_or_else(|e| panic!("{e}"));
 
-    if values.len() != count {
-        panic!("unexpected length {}, expected {}", values.len(), count);
+    // a single marker length can still be passed as the second argument, short for `--lengths`
+    let lengths = match args.get(2) {
+        Some(_) if !lengths.is_empty() => {
+            panic!("Pass the marker lengths either as the second argument or with `--lengths`!")
+        }
+        Some(length) => length.clone(),
+        None => lengths,
+    };
+    if let Some(argument) = args.get(3) {
+        panic!("Unexpected argument {argument}!");
     }
 
-    if values[1..].contains(&values[0]) {
-        return false;
-    }
+    let parse_lengths = |lengths: &str| {
+        lengths
+            .split_whitespace()
+            .map(|e| e.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>()
+            .expect("Marker lengths must be valid usizes!")
+    };
 
-    return are_unique(&values[1..], count - 1);
-}
+    if stream {
+        // the lengths of both parts unless given
+        let lengths = parse_lengths(if lengths.is_empty() { "4 14" } else { &lengths });
+        let print = |marker: day06::Marker| {
+            writeln!(
+                io::stdout(),
+                "length {}: marker at {}",
+                marker.length,
+                marker.position
+            )
+        };
+
+        let result = match args.get(1).map(String::as_str) {
+            None | Some("-") => {
+                day06::scan_stream(io::stdin().lock(), &lengths, chunk_size, every, print)
+            }
+            Some(file_path) => {
+                let file = File::open(file_path).expect("Could not read the input file!");
+                day06::scan_stream(file, &lengths, chunk_size, every, print)
+            }
+        };
+        match result {
+            Ok(read) => println!("{read} characters read"),
+            // the reading end of a pipeline is gone
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+            Err(e) => {
+                eprintln!("stream error: {e}");
+                process::exit(1);
+            }
+        }
+        return;
+    }
 
-fn main() {
-    let args = env::args().collect::<Vec<String>>();
     let file_path = args
         .get(1)
         .expect("Pass in input file path as the first argument!");
 
-    let sequence_length = args
-        .get(2)
-        .expect("Pass in the desired unique sequence length as the second parameter!")
-        .parse::<usize>()
-        .expect("Sequence length must be a valid usize!");
-
     let content = fs::read_to_string(file_path).expect("Could not read the input file!");
 
-    let mut values: Vec<char> = content
-        .chars()
-        .into_iter()
-        .take(sequence_length - 1)
-        .collect();
-
-    for (i, char) in content
-        .chars()
-        .into_iter()
-        .skip(sequence_length - 1)
-        .enumerate()
-    {
-        values.push(char);
-
-        if are_unique(&values, sequence_length) {
-            println!("result: {}", i + sequence_length);
-            break;
+    if !lengths.is_empty() {
+        let lengths = parse_lengths(&lengths);
+
+        for markers in day06::scan(&content, &lengths, all_positions) {
+            match markers.first {
+                Some(first) => println!(
+                    "length {}: first {first}, {} windows",
+                    markers.length, markers.count
+                ),
+                None => println!("length {}: no marker found", markers.length),
+            }
+
+            if !markers.positions.is_empty() {
+                let positions = markers.positions.iter().map(|e| e.to_string());
+                println!("  {}", positions.collect::<Vec<String>>().join(", "));
+            }
         }
+        return;
+    }
 
-        values = values.into_iter().skip(1).collect();
+    let mut failed = false;
+    for part in [Part::One, Part::Two] {
+        match day06::solve(&content, part) {
+            Ok(answer) => println!("{part}: {answer}"),
+            Err(e) => {
+                eprintln!("{part}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
     }
 }
@@ -0,0 +1,83 @@
+use crate::Detector;
+use std::io::{self, Read};
+use std::str;
+
+// a window of `length` different characters ending at `position`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub length: usize,
+    pub position: usize,
+}
+
+// reads the stream in chunks of `chunk_size` bytes and reports every marker as soon as its last
+// character arrives - only the first one of each length unless `every` is set, in which case it
+// keeps reading until the stream ends
+//
+// returns the number of datastream characters read, without line endings; a character split
+// between chunks waits for the next one; an error from `found`, e.g. a closed pipe, stops the scan
+pub fn scan_stream(
+    mut reader: impl Read,
+    lengths: &[usize],
+    chunk_size: usize,
+    every: bool,
+    mut found: impl FnMut(Marker) -> io::Result<()>,
+) -> io::Result<usize> {
+    let mut detector = Detector::new(0);
+    let mut pending = lengths.to_vec();
+    let mut chunk = vec![0; chunk_size.max(4)];
+    // the bytes of a character the previous chunk ended in the middle of
+    let mut carried = 0;
+
+    let mut report = |distinct: usize, position: usize, pending: &mut Vec<usize>| {
+        for &length in pending.iter().filter(|&&e| e <= distinct) {
+            found(Marker { length, position })?;
+        }
+        if !every {
+            pending.retain(|&e| e > distinct);
+        }
+        Ok::<(), io::Error>(())
+    };
+
+    report(0, 0, &mut pending)?;
+
+    while !pending.is_empty() {
+        let read = match reader.read(&mut chunk[carried..]) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        let available = carried + read;
+
+        let valid = match str::from_utf8(&chunk[..available]) {
+            Ok(text) => text,
+            Err(e) if e.error_len().is_none() => {
+                str::from_utf8(&chunk[..e.valid_up_to()]).expect("Checked by the decoder!")
+            }
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+
+        // line endings split the stream into lines, they are not part of the datastream
+        for value in valid.chars().filter(|&e| e != '\n' && e != '\r') {
+            detector.push(value);
+            report(detector.distinct(), detector.position(), &mut pending)?;
+
+            if pending.is_empty() {
+                return Ok(detector.position());
+            }
+        }
+
+        let used = valid.len();
+        chunk.copy_within(used..available, 0);
+        carried = available - used;
+    }
+
+    if carried > 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "the stream ends in the middle of a character",
+        ));
+    }
+
+    Ok(detector.position())
+}
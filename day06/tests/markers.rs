@@ -0,0 +1,36 @@
+use aoc::{Answer, Part};
+use day06::{find_marker, scan, scan_stream, solve, Marker};
+
+#[test]
+fn line_ending_is_not_a_character() {
+    assert_eq!(find_marker("abc\n", 4), None);
+    assert_eq!(find_marker("abcd\r\n", 5), None);
+    assert!(solve("abc\n", Part::One).is_err());
+    assert_eq!(
+        solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", Part::One).unwrap(),
+        Answer::Number(7)
+    );
+
+    let markers = scan("abcd\n", &[5], true);
+    assert_eq!(markers[0].first, None);
+    assert_eq!(markers[0].count, 0);
+}
+
+#[test]
+fn stream_skips_line_endings() {
+    let mut markers = vec![];
+    let read = scan_stream("ab\r\ncd\n".as_bytes(), &[4, 5], 2, true, |marker| {
+        markers.push(marker);
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(read, 4);
+    assert_eq!(
+        markers,
+        vec![Marker {
+            length: 4,
+            position: 4
+        }]
+    );
+}
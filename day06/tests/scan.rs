@@ -0,0 +1,84 @@
+use day06::{scan, Markers};
+use std::fs;
+use std::process::Command;
+
+const SAMPLE: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+fn markers(length: usize, first: Option<usize>, positions: &[usize]) -> Markers {
+    Markers {
+        length,
+        first,
+        count: positions.len(),
+        positions: positions.to_vec(),
+    }
+}
+
+// runs the binary on `input` with the arguments after the input file
+fn run(name: &str, input: &str, args: &[&str]) -> String {
+    let path = std::env::temp_dir().join(format!("day06-{name}-{}", std::process::id()));
+    fs::write(&path, input).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_day06"))
+        .arg(&path)
+        .args(args)
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn both_parts_in_one_pass() {
+    let result = scan(SAMPLE, &[4, 14], false);
+
+    assert_eq!(
+        result
+            .iter()
+            .map(|e| (e.length, e.first))
+            .collect::<Vec<_>>(),
+        [(4, Some(7)), (14, Some(19))]
+    );
+    assert!(result.iter().all(|e| e.positions.is_empty()));
+}
+
+#[test]
+fn every_position() {
+    // a, b and c are different from 3 on, the last d repeats the one before it
+    assert_eq!(
+        scan("abcabdd", &[0, 1, 3, 4, 5], true),
+        [
+            markers(0, Some(0), &[0, 1, 2, 3, 4, 5, 6, 7]),
+            markers(1, Some(1), &[1, 2, 3, 4, 5, 6, 7]),
+            markers(3, Some(3), &[3, 4, 5, 6]),
+            markers(4, Some(6), &[6]),
+            markers(5, None, &[]),
+        ]
+    );
+}
+
+#[test]
+fn counts_without_positions() {
+    let result = scan("abcabdd", &[3], false);
+
+    assert_eq!((result[0].first, result[0].count), (Some(3), 4));
+    assert!(result[0].positions.is_empty());
+}
+
+#[test]
+fn lengths_from_the_command_line() {
+    assert_eq!(
+        run("lengths", "abcabdd", &["--lengths", "3 5", "--all"]),
+        "length 3: first 3, 4 windows\n  3, 4, 5, 6\nlength 5: no marker found\n"
+    );
+}
+
+#[test]
+fn positional_length() {
+    assert_eq!(
+        run("positional", SAMPLE, &["14"]),
+        "length 14: first 19, 7 windows\n"
+    );
+    assert_eq!(run("parts", SAMPLE, &[]), "part 1: 7\npart 2: 19\n");
+}
@@ -0,0 +1,213 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct Config {
+    pub disk_size: usize,
+    pub update_size: usize,
+    pub small_dir_limit: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            disk_size: 70000000,
+            update_size: 30000000,
+            small_dir_limit: 100000,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        Ok(Self {
+            disk_size: params.take("disk_size", default.disk_size)?,
+            update_size: params.take("update_size", default.update_size)?,
+            small_dir_limit: params.take("small_dir_limit", default.small_dir_limit)?,
+        })
+    }
+}
+
+struct FileSystem {
+    directories: Vec<Directory>,
+
+    current_dir: usize,
+}
+
+struct Directory {
+    size: usize,
+    parent: Option<usize>,
+    children: HashMap<String, usize>,
+}
+
+impl FileSystem {
+    pub fn new() -> Self {
+        FileSystem {
+            directories: vec![Directory {
+                size: 0,
+                parent: None,
+                children: HashMap::new(),
+            }],
+            current_dir: 0,
+        }
+    }
+
+    pub fn get_root(&self) -> &Directory {
+        &self.directories[0]
+    }
+
+    pub fn change_dir(&mut self, name: &str) -> Result<(), String> {
+        match name {
+            "/" => {
+                self.current_dir = 0;
+            }
+            ".." => {
+                self.current_dir = self.directories[self.current_dir]
+                    .parent
+                    .ok_or("Root has no parent")?;
+            }
+            name => {
+                let current_directory = &self.directories[self.current_dir];
+                self.current_dir = *current_directory
+                    .children
+                    .get(name)
+                    .ok_or(format!("Could not find child {name}"))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn add_directory(&mut self, name: &str) {
+        let new_directory = Directory {
+            size: 0,
+            parent: Some(self.current_dir),
+            children: HashMap::new(),
+        };
+
+        self.directories.push(new_directory);
+
+        let length = self.directories.len();
+        self.directories[self.current_dir]
+            .children
+            .insert(name.to_string(), length - 1);
+    }
+
+    pub fn add_file(&mut self, size: usize) {
+        self.add_size(size, self.current_dir);
+    }
+
+    fn add_size(&mut self, size: usize, i: usize) {
+        self.directories[i].size += size;
+
+        if let Some(i) = self.directories[i].parent {
+            self.add_size(size, i);
+        }
+    }
+}
+
+// a single line of the terminal output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalLine {
+    ChangeDir(String),
+    List,
+    Dir(String),
+    File(usize, String),
+}
+
+impl fmt::Display for TerminalLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TerminalLine::ChangeDir(name) => write!(f, "$ cd {name}"),
+            TerminalLine::List => write!(f, "$ ls"),
+            TerminalLine::Dir(name) => write!(f, "dir {name}"),
+            TerminalLine::File(size, name) => write!(f, "{size} {name}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    pub lines: Vec<TerminalLine>,
+}
+
+impl Transcript {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut lines = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            lines.push(match line.split_whitespace().collect::<Vec<&str>>()[..] {
+                ["$", "ls"] => TerminalLine::List,
+                ["$", "cd", name] => TerminalLine::ChangeDir(name.to_string()),
+                ["dir", dir_name] => TerminalLine::Dir(dir_name.to_string()),
+                [size_str, file_name] => {
+                    let size = size_str.parse::<usize>().map_err(|_| {
+                        Error::parse(i + 1, format!("invalid file size {size_str}"))
+                    })?;
+                    TerminalLine::File(size, file_name.to_string())
+                }
+                _ => return Err(Error::parse(i + 1, format!("unexpected output {line}"))),
+            });
+        }
+
+        Ok(Self { lines })
+    }
+}
+
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_file_system(input: &str) -> Result<FileSystem, Error> {
+    let transcript = Transcript::parse(input)?;
+    let mut file_system = FileSystem::new();
+
+    for (i, line) in transcript.lines.iter().enumerate() {
+        match line {
+            TerminalLine::List => {}
+            TerminalLine::ChangeDir(name) => file_system
+                .change_dir(name)
+                .map_err(|e| Error::parse(i + 1, e))?,
+            TerminalLine::Dir(dir_name) => file_system.add_directory(dir_name),
+            TerminalLine::File(size, _) => file_system.add_file(*size),
+        }
+    }
+
+    Ok(file_system)
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    let file_system = parse_file_system(input)?;
+
+    match part {
+        Part::One => Ok(file_system
+            .directories
+            .iter()
+            .filter(|e| e.size <= config.small_dir_limit)
+            .fold(0, |acc, e| acc + e.size)
+            .into()),
+        Part::Two => {
+            let free_space = config.disk_size.saturating_sub(file_system.get_root().size);
+            let required_space = config.update_size.saturating_sub(free_space);
+
+            file_system
+                .directories
+                .iter()
+                .filter(|e| e.size >= required_space)
+                .reduce(|acc, e| if acc.size < e.size { acc } else { e })
+                .map(|e| e.size.into())
+                .ok_or_else(|| Error::unsolvable("no directory is large enough"))
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use aoc::{Answer, Error, Part};
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let trees = input
+        .lines()
+        .map(|e| e.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    if trees.is_empty() {
+        return Err(Error::parse(0, "empty map"));
+    }
+    if let Some(i) = trees.iter().position(|e| e.len() != trees[0].len()) {
+        return Err(Error::parse(i + 1, "rows have different lengths"));
+    }
+
+    match part {
+        Part::One => Ok(count_visible(&trees).into()),
+        Part::Two => {
+            let mut max = 0;
+            for (i, line) in trees.iter().enumerate() {
+                for (j, tree) in line.iter().enumerate() {
+                    max = std::cmp::max(max, score_tree(i, j, tree, &trees));
+                }
+            }
+
+            Ok(max.into())
+        }
+    }
+}
+
+#[allow(clippy::needless_range_loop)]
+fn count_visible(trees: &[Vec<char>]) -> usize {
+    let mut grid: Vec<Vec<usize>> = vec![];
+
+    for i in 0..trees.len() {
+        if grid.len() == i {
+            grid.push(vec![]);
+        }
+
+        let mut max = (b'0' - 1) as char;
+        for j in 0..trees[i].len() {
+            if grid[i].len() == j {
+                grid[i].push(0);
+            }
+            if trees[i][j] > max {
+                max = trees[i][j];
+                grid[i][j] += 1;
+            }
+        }
+
+        let mut max = (b'0' - 1) as char;
+        for j in (0..trees[i].len()).rev() {
+            if trees[i][j] > max {
+                max = trees[i][j];
+                grid[i][j] += 1;
+            }
+        }
+    }
+
+    for j in 0..trees[0].len() {
+        let mut max = (b'0' - 1) as char;
+        for i in 0..trees.len() {
+            if trees[i][j] > max {
+                max = trees[i][j];
+                grid[i][j] += 1;
+            }
+        }
+
+        let mut max = (b'0' - 1) as char;
+        for i in (0..trees.len()).rev() {
+            if trees[i][j] > max {
+                max = trees[i][j];
+                grid[i][j] += 1;
+            }
+        }
+    }
+
+    grid.iter().fold(0, |acc, e| {
+        acc + (e.len() - e.iter().filter(|e| **e == 0).count())
+    })
+}
+
+fn score_tree(row: usize, col: usize, tree: &char, trees: &[Vec<char>]) -> i32 {
+    [
+        score_range((0..col).rev(), |i| trees[row][i] >= *tree),
+        score_range((col + 1)..trees[row].len(), |i| trees[row][i] >= *tree),
+        score_range((0..row).rev(), |i| trees[i][col] >= *tree),
+        score_range((row + 1)..trees.len(), |i| trees[i][col] >= *tree),
+    ]
+    .iter()
+    .product()
+}
+
+fn score_range<F>(range: impl Iterator<Item = usize>, cmp: F) -> i32
+where
+    F: Fn(usize) -> bool,
+{
+    let mut result = 0;
+    for i in range {
+        result += 1;
+
+        if cmp(i) {
+            break;
+        }
+    }
+    result
+}
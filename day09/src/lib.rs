@@ -0,0 +1,175 @@
+use aoc::step::Simulation;
+use aoc::{Answer, Error, Part};
+use std::collections::HashSet;
+
+// movement vector and the number of steps
+type Motion = ((i32, i32), usize);
+
+fn parse_motions(input: &str) -> Result<Vec<Motion>, Error> {
+    let mut motions = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [direction, steps_str] => {
+                let movement = dir_to_movement(direction).ok_or_else(|| {
+                    Error::parse(i + 1, format!("Unknown direction {direction}!"))
+                })?;
+                let steps = steps_str
+                    .parse::<usize>()
+                    .map_err(|_| Error::parse(i + 1, format!("Invalid step count {steps_str}!")))?;
+
+                motions.push((movement, steps));
+            }
+            _ => return Err(Error::parse(i + 1, format!("Could not match line {line}."))),
+        }
+    }
+
+    Ok(motions)
+}
+
+// a rope moved one head step at a time
+pub struct Rope {
+    motions: Vec<Motion>,
+    next_motion: usize,
+    remaining_steps: usize,
+    head: (i32, i32),
+    tails: Vec<(i32, i32)>,
+    visited: HashSet<(i32, i32)>,
+}
+
+impl Rope {
+    pub fn new(input: &str, tail_count: usize) -> Result<Self, Error> {
+        Ok(Self::from_motions(parse_motions(input)?, tail_count))
+    }
+
+    fn from_motions(motions: Vec<Motion>, tail_count: usize) -> Self {
+        Self {
+            motions,
+            next_motion: 0,
+            remaining_steps: 0,
+            head: (0, 0),
+            tails: vec![(0, 0); tail_count],
+            visited: HashSet::from([(0, 0)]),
+        }
+    }
+
+    fn tail(&self) -> (i32, i32) {
+        *self.tails.last().unwrap_or(&self.head)
+    }
+
+    // moves the head by a single step, returns `false` once all motions are done
+    pub fn move_head(&mut self) -> bool {
+        while self.remaining_steps == 0 {
+            let Some(&(_, steps)) = self.motions.get(self.next_motion) else {
+                return false;
+            };
+            self.next_motion += 1;
+            self.remaining_steps = steps;
+        }
+
+        let (movement, _) = self.motions[self.next_motion - 1];
+        self.remaining_steps -= 1;
+        self.head = (self.head.0 + movement.0, self.head.1 + movement.1);
+
+        let mut parent = self.head;
+        for tail in self.tails.iter_mut() {
+            let tail_movement = calc_tail_movement(&parent, tail);
+            *tail = (tail.0 + tail_movement.0, tail.1 + tail_movement.1);
+            parent = *tail;
+        }
+
+        self.visited.insert(self.tail());
+
+        true
+    }
+
+    pub fn pretty_print(&self) -> String {
+        let knots = std::iter::once(self.head).chain(self.tails.iter().copied());
+        let points = knots.clone().chain(self.visited.iter().copied());
+
+        let (mut min, mut max) = ((0, 0), (0, 0));
+        for (x, y) in points {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+
+        let mut rows = vec![];
+        for y in (min.1..=max.1).rev() {
+            let mut row = String::new();
+            for x in min.0..=max.0 {
+                // the first knot on a tile wins, like in the puzzle description
+                let knot = knots.clone().position(|e| e == (x, y));
+                row.push(match knot {
+                    Some(0) => 'H',
+                    Some(_) if self.tails.len() == 1 => 'T',
+                    Some(i) => char::from_digit(i as u32, 36).unwrap_or('?'),
+                    None if (x, y) == (0, 0) => 's',
+                    None if self.visited.contains(&(x, y)) => '#',
+                    None => '.',
+                });
+            }
+            rows.push(row);
+        }
+
+        rows.join("\n")
+    }
+}
+
+impl Simulation for Rope {
+    fn step(&mut self) -> bool {
+        self.move_head()
+    }
+
+    fn render(&self) -> String {
+        self.pretty_print()
+    }
+
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("motion", self.next_motion.to_string()),
+            ("head", format!("{:?}", self.head)),
+            ("tail", format!("{:?}", self.tail())),
+            ("visited", self.visited.len().to_string()),
+        ]
+    }
+}
+
+fn count_tail_positions(motions: Vec<Motion>, tail_count: usize) -> usize {
+    let mut rope = Rope::from_motions(motions, tail_count);
+    while rope.move_head() {}
+
+    rope.visited.len()
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let motions = parse_motions(input)?;
+
+    let tail_count = match part {
+        Part::One => 1,
+        Part::Two => 9,
+    };
+
+    Ok(count_tail_positions(motions, tail_count).into())
+}
+
+fn calc_tail_movement(head: &(i32, i32), tail: &(i32, i32)) -> (i32, i32) {
+    let vector = (head.0 - tail.0, head.1 - tail.1);
+
+    // if both parts are 1 or smaller, do nothing
+    if vector.0.abs() < 2 && vector.1.abs() < 2 {
+        return (0, 0);
+    }
+
+    // else move tail - difference of 2 is clamped to 1
+    (vector.0.clamp(-1, 1), vector.1.clamp(-1, 1))
+}
+
+fn dir_to_movement(direction: &str) -> Option<(i32, i32)> {
+    match direction {
+        "U" => Some((0, 1)),
+        "D" => Some((0, -1)),
+        "L" => Some((-1, 0)),
+        "R" => Some((1, 0)),
+        _ => None,
+    }
+}
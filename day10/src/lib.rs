@@ -0,0 +1,127 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+
+pub struct Config {
+    pub first_breakpoint: usize,
+    pub breakpoint_step: usize,
+    pub display_width: usize,
+    pub display_height: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            first_breakpoint: 20,
+            breakpoint_step: 40,
+            display_width: 40,
+            display_height: 6,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        Ok(Self {
+            first_breakpoint: take_positive(params, "first_breakpoint", default.first_breakpoint)?,
+            breakpoint_step: take_positive(params, "breakpoint_step", default.breakpoint_step)?,
+            display_width: take_positive(params, "display_width", default.display_width)?,
+            display_height: take_positive(params, "display_height", default.display_height)?,
+        })
+    }
+}
+
+// cycles are counted from 1 and the display needs at least one pixel per row
+fn take_positive(params: &mut Params, key: &str, default: usize) -> Result<usize, ParamError> {
+    let value = params.take(key, default)?;
+    if value == 0 {
+        return Err(ParamError::Invalid {
+            key: key.to_string(),
+            value: value.to_string(),
+        });
+    }
+
+    Ok(value)
+}
+
+fn render_display(display: &[Vec<char>]) -> String {
+    display
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, Error> {
+    let mut instructions: Vec<Instruction> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["noop"] => instructions.push(Instruction::Noop),
+            ["addx", inc] => instructions
+                .push(Instruction::Addx(inc.parse::<i32>().map_err(|_| {
+                    Error::parse(i + 1, format!("Invalid increment {inc}."))
+                })?)),
+            _ => return Err(Error::parse(i + 1, format!("Unknown instruction {line}."))),
+        }
+    }
+
+    Ok(instructions)
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    let mut instructions = parse_instructions(input)?;
+    instructions.reverse();
+
+    let mut cycle: usize = 0;
+    let mut reg_x: i32 = 1;
+
+    let mut part1_result = 0;
+    let mut part1_result_breakpoint = config.first_breakpoint;
+
+    // the increment of an `addx` in its second cycle
+    let mut pending: Option<i32> = None;
+
+    let mut display = vec![vec!['.'; config.display_width]; config.display_height];
+
+    loop {
+        cycle += 1;
+
+        if cycle == part1_result_breakpoint {
+            part1_result += (cycle as i32) * reg_x;
+            part1_result_breakpoint += config.breakpoint_step;
+        }
+
+        let pixel = (
+            (cycle - 1) / config.display_width,
+            (cycle - 1) % config.display_width,
+        );
+        if pixel.0 < config.display_height && (reg_x - 1..=reg_x + 1).contains(&(pixel.1 as i32)) {
+            display[pixel.0][pixel.1] = '#';
+        }
+
+        match pending.take() {
+            Some(inc) => reg_x += inc,
+            None => match instructions.pop() {
+                Some(Instruction::Noop) => {}
+                Some(Instruction::Addx(inc)) => pending = Some(inc),
+                None => break,
+            },
+        }
+    }
+
+    match part {
+        Part::One => Ok(part1_result.into()),
+        Part::Two => Ok(render_display(&display).into()),
+    }
+}
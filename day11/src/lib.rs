@@ -0,0 +1,408 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+use std::collections::HashMap;
+use std::fmt;
+
+// this was originally supposed to be much nicer/cleaner
+// but then some closure issues appeared
+// and then part 2 happened
+
+#[derive(Debug)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: Operation,
+    test: Test,
+    inspection_count: i64,
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    first: Operand,
+    op: Operator,
+    second: Operand,
+}
+
+#[derive(Debug)]
+enum Operand {
+    Old,
+    Number(i64),
+}
+
+impl Operand {
+    fn parse(operand: &str) -> Option<Self> {
+        match operand {
+            "old" => Some(Operand::Old),
+            number => number.parse::<i64>().ok().map(Operand::Number),
+        }
+    }
+
+    fn value(&self, old: i64) -> i64 {
+        match self {
+            Operand::Old => old,
+            Operand::Number(number) => *number,
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Old => write!(f, "old"),
+            Operand::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let op = match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+        };
+        write!(f, "{op}")
+    }
+}
+
+impl Operation {
+    pub fn new(first: &str, op: &str, second: &str) -> Result<Self, String> {
+        let (Some(first), Some(second)) = (Operand::parse(first), Operand::parse(second)) else {
+            return Err(format!("Invalid operands {first} and {second}."));
+        };
+        let op = match op {
+            "+" => Operator::Add,
+            "-" => Operator::Subtract,
+            "*" => Operator::Multiply,
+            "/" => Operator::Divide,
+            _ => return Err(format!("Unexpected operator {op}.")),
+        };
+        if let (Operator::Divide, Operand::Number(0)) = (&op, &second) {
+            return Err("Division by zero.".to_string());
+        }
+
+        Ok(Operation { first, op, second })
+    }
+
+    pub fn call(&self, i: i64) -> i64 {
+        let (first, second) = (self.first.value(i), self.second.value(i));
+
+        match self.op {
+            Operator::Add => first + second,
+            Operator::Subtract => first - second,
+            Operator::Multiply => first * second,
+            Operator::Divide => first / second,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "new = {} {} {}", self.first, self.op, self.second)
+    }
+}
+
+#[derive(Debug)]
+pub struct Test {
+    divisor: i64,
+    on_true: usize,
+    on_false: usize,
+}
+
+impl Test {
+    pub fn new(divisor: i64, on_true: usize, on_false: usize) -> Self {
+        Self {
+            divisor,
+            on_true,
+            on_false,
+        }
+    }
+
+    pub fn call(&self, item: &Item) -> usize {
+        if item.tests[&self.divisor].divisible {
+            return self.on_true;
+        }
+        self.on_false
+    }
+}
+
+impl Monkey {
+    pub fn new(items: Vec<Item>, operation: Operation, test: Test) -> Self {
+        Monkey {
+            items,
+            operation,
+            test,
+            inspection_count: 0,
+        }
+    }
+
+    pub fn from_string(string: &str) -> Result<(usize, Self), String> {
+        let mut lines = string.lines();
+        let mut next_line = |name: &str| {
+            lines
+                .next()
+                .ok_or(format!("Could not find the monkey's {name}."))
+        };
+
+        let number = match next_line("number")?
+            .replace(':', "")
+            .split_whitespace()
+            .collect::<Vec<&str>>()[..]
+        {
+            ["Monkey", number] => number
+                .parse::<usize>()
+                .map_err(|_| "Expected monkey's number.".to_string())?,
+            _ => return Err("Could not find the monkey's number.".to_string()),
+        };
+
+        let items = match next_line("items")?.trim().split(':').collect::<Vec<&str>>()[..] {
+            ["Starting items", items] => items
+                .split(',')
+                .map(|e| e.trim().parse::<i64>().map(Item::new))
+                .collect::<Result<Vec<Item>, _>>()
+                .map_err(|_| format!("Invalid starting items {items}."))?,
+            _ => return Err("Could not find the monkey's items.".to_string()),
+        };
+
+        let operation = match next_line("inspect operation")?
+            .trim()
+            .split(':')
+            .collect::<Vec<&str>>()[..]
+        {
+            ["Operation", operation] => {
+                let ops: Vec<&str> = operation.split_whitespace().collect();
+
+                match ops[..] {
+                    ["new", "=", first, op, second] => Operation::new(first, op, second)?,
+                    _ => return Err(format!("Unexpected operation {operation}.")),
+                }
+            }
+            _ => return Err("Could not find the monkey's inspect operation.".to_string()),
+        };
+
+        let test_divisor = match next_line("test")?.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["Test:", "divisible", "by", number] => match number.parse::<i64>() {
+                Ok(divisor) if divisor != 0 => divisor,
+                _ => return Err(format!("Invalid divisor {number}.")),
+            },
+            _ => return Err("Could not find the monkey's test.".to_string()),
+        };
+        let test_true = match next_line("true target")?
+            .split_whitespace()
+            .collect::<Vec<&str>>()[..]
+        {
+            ["If", "true:", "throw", "to", "monkey", number] => number
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid target monkey {number}."))?,
+            _ => return Err("Could not find the monkey's true target.".to_string()),
+        };
+        let test_false = match next_line("false target")?
+            .split_whitespace()
+            .collect::<Vec<&str>>()[..]
+        {
+            ["If", "false:", "throw", "to", "monkey", number] => number
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid target monkey {number}."))?,
+            _ => return Err("Could not find the monkey's false target.".to_string()),
+        };
+
+        Ok((
+            number,
+            Monkey::new(
+                items,
+                operation,
+                Test::new(test_divisor, test_true, test_false),
+            ),
+        ))
+    }
+}
+
+// the monkey's notes without the `Monkey N:` header
+impl fmt::Display for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let items = self
+            .items
+            .iter()
+            .map(|e| e.original.to_string())
+            .collect::<Vec<String>>();
+
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: {}", self.operation)?;
+        writeln!(f, "  Test: divisible by {}", self.test.divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.test.on_true)?;
+        write!(f, "    If false: throw to monkey {}", self.test.on_false)
+    }
+}
+
+// the notes of all monkeys, in order
+#[derive(Debug)]
+pub struct Notes {
+    pub monkeys: Vec<Monkey>,
+}
+
+impl Notes {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut monkeys: Vec<Monkey> = Vec::new();
+        let mut line = 1;
+
+        for block in input.split("\n\n") {
+            let (i, monkey) = Monkey::from_string(block).map_err(|e| Error::parse(line, e))?;
+            if i > monkeys.len() {
+                return Err(Error::parse(line, format!("Monkey {i} is out of order.")));
+            }
+            monkeys.insert(i, monkey);
+
+            line += block.lines().count() + 1;
+        }
+
+        for monkey in &monkeys {
+            if monkey.test.on_true >= monkeys.len() || monkey.test.on_false >= monkeys.len() {
+                return Err(Error::parse(0, "Items are thrown to an unknown monkey."));
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+}
+
+impl fmt::Display for Notes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "Monkey {i}:")?;
+            writeln!(f, "{monkey}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Config {
+    pub rounds: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { rounds: 10000 }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        Ok(Self {
+            rounds: params.take("rounds", default.rounds)?,
+        })
+    }
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+// only part 2 is implemented, the remainder trick below does not work with division
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    if part == Part::One {
+        return Err(Error::Unsupported(part));
+    }
+
+    let mut monkeys = Notes::parse(input)?.monkeys;
+
+    let divisors = monkeys.iter().map(|e| e.test.divisor).collect::<Vec<i64>>();
+
+    for monkey in &mut monkeys {
+        for item in &mut monkey.items {
+            for divisor in &divisors {
+                item.add_test(*divisor)
+            }
+        }
+    }
+
+    for _ in 0..config.rounds {
+        for i in 0..monkeys.len() {
+            while let Some(mut item) = monkeys[i].items.pop() {
+                item.update(&monkeys[i].operation);
+
+                let new_index = monkeys[i].test.call(&item);
+
+                monkeys[i].inspection_count += 1;
+                monkeys[new_index].items.push(item);
+            }
+        }
+    }
+
+    let mut monkey_business = monkeys
+        .iter()
+        .map(|e| e.inspection_count)
+        .collect::<Vec<i64>>();
+    monkey_business.sort();
+
+    let result: i64 = monkey_business.iter().rev().take(2).product();
+
+    Ok(result.into())
+}
+
+// the idea is that since the only operations are multiplication and addition
+// we can store only the remainder for each monkey
+// each inspection then updates all posible results for each monkey for current item
+
+// (A * B) mod C = (A mod C * B mod C) mod C
+// (A + B) mod C = (A mod C + B mod C) mod C
+
+#[derive(Debug)]
+pub struct Item {
+    original: i64,
+    tests: HashMap<i64, ItemTest>,
+}
+
+impl Item {
+    pub fn new(original: i64) -> Self {
+        Item {
+            original,
+            tests: HashMap::new(),
+        }
+    }
+
+    pub fn add_test(&mut self, divisor: i64) {
+        self.tests
+            .insert(divisor, ItemTest::new(divisor, self.original));
+    }
+
+    pub fn update(&mut self, operation: &Operation) {
+        for test in self.tests.values_mut() {
+            test.update(operation);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ItemTest {
+    divisor: i64,
+    n: i64,
+    divisible: bool,
+}
+
+impl ItemTest {
+    pub fn new(divisor: i64, n: i64) -> Self {
+        ItemTest {
+            divisor,
+            n,
+            divisible: n % divisor == 0,
+        }
+    }
+
+    pub fn update(&mut self, operation: &Operation) {
+        self.n = operation.call(self.n) % self.divisor;
+        self.divisible = self.n == 0;
+    }
+}
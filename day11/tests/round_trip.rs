@@ -0,0 +1,11 @@
+use day11::Notes;
+
+const SAMPLE: &str = include_str!("../sample");
+
+// the notes are written back as a file, ending in a newline the bundled sample lacks
+#[test]
+fn notes_round_trip() {
+    let input = format!("{SAMPLE}\n");
+    assert_eq!(Notes::parse(&input).unwrap().to_string(), input);
+    assert_eq!(Notes::parse(SAMPLE).unwrap().to_string(), input);
+}
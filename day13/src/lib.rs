@@ -0,0 +1,207 @@
+use aoc::{Answer, Error, Part};
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Debug)]
+pub enum Value {
+    Number(usize),
+    Vector(Vec<Value>),
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match compare_signals(self, other) {
+            Some(true) => std::cmp::Ordering::Less,
+            None => std::cmp::Ordering::Equal,
+            Some(false) => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{number}"),
+            Value::Vector(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+fn char_to_usize(c: &char) -> Result<usize, String> {
+    match c {
+        '0'..='9' => Ok((*c as u8 - b'0') as usize),
+        _ => Err(format!("Expected number, got {c}.")),
+    }
+}
+
+fn parse_signal(chars: &mut Peekable<Chars>) -> Result<Option<Value>, String> {
+    match chars.next() {
+        Some('[') => {
+            let mut values: Vec<Value> = vec![];
+            while let Some(value) = parse_signal(chars)? {
+                values.push(value);
+
+                match chars.next() {
+                    Some(',') => (),
+                    Some(']') => break,
+                    c => return Err(format!("Expected ']' or ',', got {c:?}.")),
+                }
+            }
+            Ok(Some(Value::Vector(values)))
+        }
+        Some(']') => Ok(None),
+        Some(c) => {
+            let mut number = char_to_usize(&c)?;
+            while let Some(c) = chars.next_if(|e| *e != ',' && *e != ']') {
+                number *= 10;
+                number += char_to_usize(&c)?;
+            }
+            Ok(Some(Value::Number(number)))
+        }
+        None => Err("Unexpected end of the signal.".to_string()),
+    }
+}
+
+fn parse_line(line: &str, line_number: usize) -> Result<Value, Error> {
+    let mut chars = line.chars().peekable();
+    let value = parse_signal(&mut chars)
+        .map_err(|e| Error::parse(line_number, e))?
+        .ok_or_else(|| Error::parse(line_number, "Expected a signal."))?;
+
+    if chars.next().is_some() {
+        return Err(Error::parse(
+            line_number,
+            "Unexpected characters after the signal.",
+        ));
+    }
+
+    Ok(value)
+}
+
+fn compare_signals(left: &Value, right: &Value) -> Option<bool> {
+    match (left, right) {
+        (Value::Number(l), Value::Number(r)) => {
+            if l == r {
+                return None;
+            }
+            Some(l < r)
+        }
+        (Value::Vector(l), Value::Vector(r)) => {
+            for (left_zip, right_zip) in l.iter().zip(r.iter()) {
+                if let Some(result) = compare_signals(left_zip, right_zip) {
+                    return Some(result);
+                };
+            }
+
+            compare_signals(&Value::Number(l.len()), &Value::Number(r.len()))
+        }
+        (Value::Number(number), Value::Vector(_)) => {
+            compare_signals(&Value::Vector(vec![Value::Number(*number)]), right)
+        }
+        (Value::Vector(_), Value::Number(number)) => {
+            compare_signals(left, &Value::Vector(vec![Value::Number(*number)]))
+        }
+    }
+}
+
+// the received packets, in pairs
+#[derive(Debug)]
+pub struct DistressSignal {
+    pub pairs: Vec<(Value, Value)>,
+}
+
+impl DistressSignal {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut pairs = vec![];
+        let mut line = 1;
+
+        for block in input.split("\n\n") {
+            match block.lines().collect::<Vec<&str>>()[..] {
+                [left, right] => {
+                    pairs.push((parse_line(left, line)?, parse_line(right, line + 1)?));
+                }
+                _ => return Err(Error::parse(line, "Expected a pair of signals.")),
+            }
+            line += block.lines().count() + 1;
+        }
+
+        Ok(Self { pairs })
+    }
+}
+
+impl fmt::Display for DistressSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (left, right)) in self.pairs.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{left}")?;
+            writeln!(f, "{right}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let signal = DistressSignal::parse(input)?;
+
+    match part {
+        Part::One => {
+            let mut result = 0;
+            for (i, (left, right)) in signal.pairs.iter().enumerate() {
+                if let Some(true) = compare_signals(left, right) {
+                    result += i + 1;
+                }
+            }
+
+            Ok(result.into())
+        }
+        Part::Two => {
+            let mut signals = signal
+                .pairs
+                .into_iter()
+                .flat_map(|(left, right)| [left, right])
+                .collect::<Vec<Value>>();
+
+            signals.push(Value::Vector(vec![Value::Vector(vec![Value::Number(2)])]));
+            signals.push(Value::Vector(vec![Value::Vector(vec![Value::Number(6)])]));
+
+            signals.sort();
+
+            let d1 = signals
+                .iter()
+                .position(|e| *e == Value::Vector(vec![Value::Vector(vec![Value::Number(2)])]))
+                .expect("First part of the divider was not found!");
+            let d2 = signals
+                .iter()
+                .position(|e| *e == Value::Vector(vec![Value::Vector(vec![Value::Number(6)])]))
+                .expect("Second part of the divider was not found!");
+
+            Ok(((d1 + 1) * (d2 + 1)).into())
+        }
+    }
+}
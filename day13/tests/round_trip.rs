@@ -0,0 +1,11 @@
+use day13::DistressSignal;
+
+const SAMPLE: &str = include_str!("../sample");
+
+// the packets are written back as a file, ending in a newline the bundled sample lacks
+#[test]
+fn packets_round_trip() {
+    let input = format!("{SAMPLE}\n");
+    assert_eq!(DistressSignal::parse(&input).unwrap().to_string(), input);
+    assert_eq!(DistressSignal::parse(SAMPLE).unwrap().to_string(), input);
+}
@@ -0,0 +1,305 @@
+use aoc::step::Simulation;
+use aoc::{Answer, Error, Part};
+
+#[derive(Debug, Clone)]
+enum Point {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Point {
+    fn to_string(&self) -> &str {
+        match self {
+            Point::Air => " ",
+            Point::Rock => "#",
+            Point::Sand => "o",
+        }
+    }
+}
+
+fn pretty_print_scan(scan: &[Vec<Point>]) -> String {
+    let mut result = String::new();
+    for line in scan {
+        result.push_str(
+            &line
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<&str>>()
+                .join(""),
+        );
+        result.push('\n');
+    }
+    result.push_str(&"=".repeat(scan[0].len()));
+    result
+}
+
+fn parse_rocks(input: &str) -> Result<Vec<Vec<(usize, usize)>>, Error> {
+    let mut rocks: Vec<Vec<(usize, usize)>> = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let mut rock: Vec<(usize, usize)> = vec![];
+
+        for point in line
+            .split(" -> ")
+            .map(|e| e.split(',').collect::<Vec<&str>>())
+        {
+            let (x, y) = match point[..] {
+                [x, y] => match (x.parse::<usize>(), y.parse::<usize>()) {
+                    (Ok(x), Ok(y)) => (x, y),
+                    _ => {
+                        return Err(Error::parse(
+                            i + 1,
+                            format!("Expected point, got {point:?}"),
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(Error::parse(
+                        i + 1,
+                        format!("Expected point, got {point:?}"),
+                    ))
+                }
+            };
+
+            rock.push((x, y));
+        }
+        rocks.push(rock);
+    }
+
+    Ok(rocks)
+}
+
+pub struct Cave {
+    scan: Vec<Vec<Point>>,
+    offset: usize,
+    origin: usize,
+    // units of sand at rest and where the last one stopped
+    sand: usize,
+    last: Option<(usize, usize)>,
+}
+
+impl Cave {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let rocks = parse_rocks(input)?;
+
+        let mut x_range: (usize, usize) = (usize::MAX, usize::MIN);
+        let mut max_depth: usize = usize::MIN;
+
+        for &(x, y) in rocks.iter().flatten() {
+            if x < x_range.0 {
+                x_range = (x, x_range.1);
+            }
+            if x > x_range.1 {
+                x_range = (x_range.0, x);
+            }
+            if y > max_depth {
+                max_depth = y;
+            }
+        }
+
+        // the sand source has to be inside the scan
+        x_range = (x_range.0.min(500), x_range.1.max(500));
+        max_depth += 2;
+
+        let mut scan = vec![vec![Point::Air; x_range.1 - x_range.0 + 1]; max_depth + 1];
+        let offset = x_range.0;
+
+        for (i, rock) in rocks.iter().enumerate() {
+            for (p1, p2) in rock.iter().zip(rock.iter().skip(1)) {
+                match (p1.0 == p2.0, p1.1 == p2.1) {
+                    (true, _) => {
+                        let range = [p1.1.min(p2.1), p1.1.max(p2.1)];
+
+                        for row in scan.iter_mut().take(range[1] + 1).skip(range[0]) {
+                            row[p1.0 - offset] = Point::Rock;
+                        }
+                    }
+                    (false, true) => {
+                        let range = [p1.0.min(p2.0), p1.0.max(p2.0)];
+
+                        for point in &mut scan[p1.1][(range[0] - offset)..=(range[1] - offset)] {
+                            *point = Point::Rock;
+                        }
+                    }
+                    _ => return Err(Error::parse(i + 1, "Rock lines must be straight")),
+                }
+            }
+        }
+
+        let last_row_index = scan.len() - 1;
+        for point in scan[last_row_index].iter_mut() {
+            *point = Point::Rock;
+        }
+
+        Ok(Self {
+            scan,
+            offset,
+            origin: 500 - offset,
+            sand: 0,
+            last: None,
+        })
+    }
+
+    pub fn pretty_print(&self) -> String {
+        pretty_print_scan(&self.scan)
+    }
+
+    // the floor is the last row of the scan
+    pub fn floor(&self) -> usize {
+        self.scan.len() - 1
+    }
+
+    // drops a single unit of sand and returns where it came to rest (x, y)
+    // returns `None` once the source is blocked
+    pub fn drop_sand(&mut self) -> Option<(usize, usize)> {
+        let mut position = (self.origin as i32, 0);
+
+        if let Some(Point::Rock) | Some(Point::Sand) = get_point(&self.scan, position) {
+            return None;
+        }
+
+        loop {
+            match get_point(&self.scan, (position.0, position.1 + 1)) {
+                // next position is air -> continue
+                Some(Point::Air) => {
+                    position = (position.0, position.1 + 1);
+                    continue;
+                }
+                // next position is sand or rock
+                Some(Point::Sand) | Some(Point::Rock) => {
+                    // check the position to the left, if air -> continue
+                    match get_point(&self.scan, (position.0 - 1, position.1 + 1)) {
+                        Some(Point::Air) => {
+                            position = (position.0 - 1, position.1 + 1);
+                            continue;
+                        }
+                        None => {
+                            // out of bounds -> expand
+                            expand(&mut self.scan, position.0 == 0);
+                            if position.0 == 0 {
+                                position = (position.0 + 1, position.1);
+                                self.offset -= 1;
+                                self.origin += 1;
+                            }
+                            continue;
+                        }
+                        _ => (),
+                    }
+
+                    // check the position to the right, if air -> continue
+                    match get_point(&self.scan, (position.0 + 1, position.1 + 1)) {
+                        Some(Point::Air) => {
+                            position = (position.0 + 1, position.1 + 1);
+                            continue;
+                        }
+                        None => {
+                            // out of bounds -> expand
+                            expand(&mut self.scan, position.0 == 0);
+                            if position.0 == 0 {
+                                position = (position.0 + 1, position.1);
+                                self.offset -= 1;
+                                self.origin += 1;
+                            }
+
+                            continue;
+                        }
+                        _ => (),
+                    }
+
+                    // else place sand
+                    self.scan[position.1][position.0 as usize] = Point::Sand;
+                    self.sand += 1;
+                    self.last = Some((position.0 as usize + self.offset, position.1));
+                    return self.last;
+                }
+                // the floor spans the whole scan, so sand never falls out
+                None => unreachable!("Ran out of bounds."),
+            }
+        }
+    }
+}
+
+impl Simulation for Cave {
+    fn step(&mut self) -> bool {
+        self.drop_sand().is_some()
+    }
+
+    fn render(&self) -> String {
+        self.pretty_print()
+    }
+
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let position = match self.last {
+            Some(position) => format!("{position:?}"),
+            None => "-".to_string(),
+        };
+
+        vec![
+            ("sand", self.sand.to_string()),
+            ("position", position),
+            ("floor", self.floor().to_string()),
+        ]
+    }
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let mut cave = Cave::new(input)?;
+    let mut counter = 0;
+
+    while let Some((_, y)) = cave.drop_sand() {
+        // without the floor, the first sand resting on it would fall into the abyss
+        if part == Part::One && y == cave.floor() - 1 {
+            break;
+        }
+
+        counter += 1;
+    }
+
+    Ok(counter.into())
+}
+
+fn get_point(scan: &[Vec<Point>], (x, y): (i32, usize)) -> Option<&Point> {
+    if x < 0 || (x as usize) >= scan[0].len() {
+        return None;
+    }
+    scan.get(y)?.get(x as usize)
+}
+
+fn expand(scan: &mut [Vec<Point>], start: bool) {
+    let length = scan.len();
+
+    if start {
+        for (i, line) in scan.iter_mut().enumerate() {
+            line.insert(
+                0,
+                if i == length - 1 {
+                    Point::Rock
+                } else {
+                    Point::Air
+                },
+            );
+        }
+        return;
+    }
+
+    for (i, line) in scan.iter_mut().enumerate() {
+        line.push(if i == length - 1 {
+            Point::Rock
+        } else {
+            Point::Air
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let sample = include_str!("../sample");
+        assert_eq!(solve(sample, Part::One), Ok(Answer::Number(24)));
+        assert_eq!(solve(sample, Part::Two), Ok(Answer::Number(93)));
+    }
+}
@@ -0,0 +1,271 @@
+use aoc::params::{ParamError, Params};
+use aoc::{Answer, Error, Part};
+use std::ops::RangeInclusive;
+
+pub struct Config {
+    pub row: i32,
+    pub search_bound: i32,
+    pub tuning_multiplier: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            search_bound: 4000000,
+            tuning_multiplier: 4000000,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        Ok(Self {
+            row: params.take("row", default.row)?,
+            search_bound: params.take("search_bound", default.search_bound)?,
+            tuning_multiplier: params.take("tuning_multiplier", default.tuning_multiplier)?,
+        })
+    }
+}
+
+struct Pair {
+    sensor: (i32, i32),
+    radius: i32,
+}
+
+impl Pair {
+    pub fn new(sensor: (i32, i32), beacon: (i32, i32)) -> Self {
+        Self {
+            sensor,
+            radius: distance(sensor, beacon),
+        }
+    }
+
+    pub fn get_intersection_range(&self, y: i32) -> Option<RangeInclusive<i32>> {
+        let x = self.radius - (y - self.sensor.1).abs();
+        if x < 0 {
+            return None;
+        }
+
+        Some((self.sensor.0 - x)..=(self.sensor.0 + x))
+    }
+}
+
+struct Map {
+    pairs: Vec<Pair>,
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Self { pairs: vec![] }
+    }
+
+    pub fn add_pair(&mut self, sensor: (i32, i32), beacon: (i32, i32)) {
+        self.pairs.push(Pair::new(sensor, beacon))
+    }
+
+    fn merge_at_line(&self, y: i32) -> Option<Vec<RangeInclusive<i32>>> {
+        let mut intersections = vec![];
+
+        for pair in &self.pairs {
+            if let Some(intersection) = pair.get_intersection_range(y) {
+                intersections.push(intersection);
+            }
+        }
+
+        if intersections.is_empty() {
+            return None;
+        }
+
+        let mut merged_intersections = vec![];
+        let mut current_intersection = intersections.pop().unwrap();
+        'outer: loop {
+            for (i, intersection) in merged_intersections.iter().enumerate() {
+                if let Some(merge) = merge_ranges(&current_intersection, intersection) {
+                    current_intersection = merge;
+                    merged_intersections.remove(i);
+                    continue 'outer;
+                }
+            }
+
+            merged_intersections.push(current_intersection.clone());
+            match intersections.pop() {
+                Some(intersection) => current_intersection = intersection,
+                None => break,
+            }
+        }
+
+        Some(merged_intersections)
+    }
+
+    pub fn scan_line(&self, y: i32) -> Option<i32> {
+        if let Some(merged_intersections) = self.merge_at_line(y) {
+            return Some(
+                merged_intersections
+                    .iter()
+                    .map(|e| e.end() - e.start())
+                    .sum(),
+            );
+        }
+
+        None
+    }
+
+    // not pretty/optimal, but its getting too late
+    pub fn check_range_at_line(&self, y: i32, range: &RangeInclusive<i32>) -> Option<i32> {
+        let merged_intersections = self.merge_at_line(y)?;
+
+        let mut remaining_ranges = vec![range.clone()];
+        for intersection in merged_intersections {
+            'inner: loop {
+                for (i, range) in remaining_ranges.iter().enumerate() {
+                    match subract_ranges(range, &intersection) {
+                        RangeDifference::Full => {}
+                        RangeDifference::OneRange(difference) => {
+                            remaining_ranges[i] = difference;
+                            continue 'inner;
+                        }
+                        RangeDifference::TwoRanges(difference1, difference2) => {
+                            remaining_ranges[i] = difference1;
+                            remaining_ranges.push(difference2);
+                            continue 'inner;
+                        }
+                        RangeDifference::Nothing => {
+                            remaining_ranges.remove(i);
+                            continue 'inner;
+                        }
+                    }
+                }
+                if remaining_ranges.is_empty() {
+                    return None;
+                }
+
+                break 'inner;
+            }
+        }
+
+        match &remaining_ranges[..] {
+            [result] if result.start() == result.end() => Some(*result.start()),
+            _ => None,
+        }
+    }
+}
+
+fn distance(sensor: (i32, i32), beacon: (i32, i32)) -> i32 {
+    (sensor.0 - beacon.0).abs() + (sensor.1 - beacon.1).abs()
+}
+
+fn merge_ranges(
+    input: &RangeInclusive<i32>,
+    other: &RangeInclusive<i32>,
+) -> Option<RangeInclusive<i32>> {
+    match (other.contains(input.start()), other.contains(input.end())) {
+        (true, true) => return Some(other.clone()),
+        (true, false) => return Some(*other.start()..=*input.end()),
+        (false, true) => return Some(*input.start()..=*other.end()),
+        (false, false) => {}
+    }
+
+    if input.contains(other.start()) && input.contains(other.end()) {
+        return Some(input.clone());
+    }
+
+    if input.end() + 1 == *other.start() {
+        return Some(*input.start()..=*other.end());
+    }
+
+    if other.end() + 1 == *input.start() {
+        return Some(*other.start()..=*input.end());
+    }
+
+    None
+}
+
+#[derive(Debug)]
+enum RangeDifference {
+    Full,
+    OneRange(RangeInclusive<i32>),
+    TwoRanges(RangeInclusive<i32>, RangeInclusive<i32>),
+    Nothing,
+}
+
+fn subract_ranges(
+    minuend: &RangeInclusive<i32>,
+    subtrahend: &RangeInclusive<i32>,
+) -> RangeDifference {
+    match (
+        subtrahend.contains(minuend.start()),
+        subtrahend.contains(minuend.end()),
+    ) {
+        (true, true) => return RangeDifference::Nothing,
+        (true, false) => return RangeDifference::OneRange((subtrahend.end() + 1)..=*minuend.end()),
+        (false, true) => {
+            return RangeDifference::OneRange(*minuend.start()..=(subtrahend.start() - 1))
+        }
+        (false, false) => {}
+    }
+
+    if minuend.contains(subtrahend.start()) && minuend.contains(subtrahend.end()) {
+        return RangeDifference::TwoRanges(
+            *minuend.start()..=(subtrahend.start() - 1),
+            (subtrahend.end() + 1)..=*minuend.end(),
+        );
+    }
+
+    RangeDifference::Full
+}
+
+fn parse_coordinate(input: &str) -> Option<i32> {
+    let input = input.replace([',', ':'], "");
+    match input.split('=').collect::<Vec<&str>>()[..] {
+        ["y" | "x", n] => n.parse::<i32>().ok(),
+        _ => None,
+    }
+}
+
+fn parse_map(input: &str) -> Result<Map, Error> {
+    let mut map = Map::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let coordinates = match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["Sensor", "at", s_x, s_y, "closest", "beacon", "is", "at", b_x, b_y] => {
+                [s_x, s_y, b_x, b_y].map(parse_coordinate)
+            }
+            _ => return Err(Error::parse(i + 1, format!("Unexpected input: {line}"))),
+        };
+
+        match coordinates {
+            [Some(s_x), Some(s_y), Some(b_x), Some(b_y)] => map.add_pair((s_x, s_y), (b_x, b_y)),
+            _ => return Err(Error::parse(i + 1, format!("Invalid coordinates: {line}"))),
+        }
+    }
+
+    Ok(map)
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    let map = parse_map(input)?;
+
+    match part {
+        Part::One => map
+            .scan_line(config.row)
+            .map(|e| e.into())
+            .ok_or_else(|| Error::unsolvable(format!("no sensor reaches row {}", config.row))),
+        Part::Two => {
+            let checked_range = 0..=config.search_bound;
+            for y in 0..=config.search_bound {
+                if let Some(x) = map.check_range_at_line(y, &checked_range) {
+                    return Ok((x as i64 * config.tuning_multiplier + y as i64).into());
+                }
+            }
+
+            Err(Error::unsolvable("the distress beacon was not found"))
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use aoc::params::{ParamError, Params};
+use aoc::step::Simulation;
+use aoc::{Answer, Error, Part};
+use std::collections::HashMap;
+use std::fmt;
+use std::vec;
+
+const PART_2_ROCKS: usize = 1000000000000;
+
+// rows at the top of the cavern compared when looking for a cycle
+const SURFACE_DEPTH: usize = 32;
+
+// next shape, next motion and the surface
+type State = (usize, usize, Vec<Vec<bool>>);
+
+pub struct Config {
+    pub rocks: usize,
+    pub cavern_width: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rocks: 2022,
+            cavern_width: 7,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        Ok(Self {
+            rocks: params.take("rocks", default.rocks)?,
+            cavern_width: params.take("cavern_width", default.cavern_width)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Point {
+    Air,
+    Rock,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Point::Air => write!(f, "."),
+            Point::Rock => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Motion {
+    Left,
+    Right,
+    Down,
+}
+
+impl Motion {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Self::Left),
+            '>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    pub fn get_next_index(&self, index: (usize, usize), width: usize) -> Option<(usize, usize)> {
+        match self {
+            Motion::Left => {
+                if index.1 == 0 {
+                    return None;
+                }
+                Some((index.0, index.1 - 1))
+            }
+            Motion::Right => {
+                if index.1 >= width - 1 {
+                    return None;
+                }
+                Some((index.0, index.1 + 1))
+            }
+            Motion::Down => {
+                if index.0 == 0 {
+                    return None;
+                }
+                Some((index.0 - 1, index.1))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Shape {
+    HorizontalLine,
+    Cross,
+    FlippedL,
+    VerticalLine,
+    Square,
+}
+
+impl Shape {
+    pub fn is_motion_possible(
+        &self,
+        cavern: &Cavern,
+        index: (usize, usize),
+        motion: Motion,
+    ) -> Option<(usize, usize)> {
+        let next_index = motion.get_next_index(index, cavern.width)?;
+
+        // TODO add diferent `get_indices` just for collision checking?
+        if self
+            .get_indices(next_index)
+            .into_iter()
+            .all(|e| cavern.is_point_empty(e))
+        {
+            return Some(next_index);
+        }
+
+        None
+    }
+
+    pub fn get_indices(&self, index: (usize, usize)) -> Vec<(usize, usize)> {
+        match self {
+            // ####
+            Shape::HorizontalLine => vec![
+                (index.0, index.1),
+                (index.0, index.1 + 1),
+                (index.0, index.1 + 2),
+                (index.0, index.1 + 3),
+            ],
+            // .#.
+            // ###
+            // .#.
+            Shape::Cross => vec![
+                (index.0 + 1, index.1),
+                (index.0 + 2, index.1 + 1),
+                (index.0 + 1, index.1 + 1),
+                (index.0, index.1 + 1),
+                (index.0 + 1, index.1 + 2),
+            ],
+            // ..#
+            // ..#
+            // ###
+            Shape::FlippedL => vec![
+                (index.0, index.1),
+                (index.0, index.1 + 1),
+                (index.0 + 2, index.1 + 2),
+                (index.0 + 1, index.1 + 2),
+                (index.0, index.1 + 2),
+            ],
+            // #
+            // #
+            // #
+            // #
+            Shape::VerticalLine => vec![
+                (index.0 + 3, index.1),
+                (index.0 + 2, index.1),
+                (index.0 + 1, index.1),
+                (index.0, index.1),
+            ],
+            // ##
+            // ##
+            Shape::Square => vec![
+                (index.0 + 1, index.1),
+                (index.0, index.1),
+                (index.0 + 1, index.1 + 1),
+                (index.0, index.1 + 1),
+            ],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Cavern {
+    motion_sequence: Vec<Motion>,
+    motion_index: usize,
+    board: Vec<Vec<Point>>,
+    width: usize,
+    rock_counter: i64,
+}
+
+impl Cavern {
+    pub fn new(air_sequence: &str, width: usize) -> Result<Self, Error> {
+        let motion_sequence = air_sequence
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(i, e)| {
+                Motion::from_char(e)
+                    .ok_or_else(|| Error::parse(1, format!("Unknown motion {e} at {i}!")))
+            })
+            .collect::<Result<Vec<Motion>, Error>>()?;
+
+        if motion_sequence.is_empty() {
+            return Err(Error::parse(1, "Empty motion sequence!"));
+        }
+        if width < 4 {
+            return Err(Error::unsolvable("The cavern is too narrow for the rocks!"));
+        }
+
+        Ok(Self {
+            motion_sequence,
+            motion_index: 0,
+            board: vec![],
+            width,
+            rock_counter: 0,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.board.len()
+    }
+
+    pub fn pretty_print(&self) -> String {
+        let mut result = String::new();
+        for line in self.board.iter().rev() {
+            result.push_str(&format!(
+                "|{}|\n",
+                line.iter().map(|e| e.to_string()).collect::<String>()
+            ));
+        }
+        result.push_str(&format!("+{}+", vec!["-"; self.width].join("")));
+        result
+    }
+
+    // the top of the board together with the next shape and motion identify the state
+    fn surface(&self) -> Vec<Vec<bool>> {
+        self.board
+            .iter()
+            .rev()
+            .take(SURFACE_DEPTH)
+            .map(|line| line.iter().map(|e| matches!(e, Point::Rock)).collect())
+            .collect()
+    }
+
+    pub fn is_point_empty(&self, index: (usize, usize)) -> bool {
+        if self.width <= index.1 {
+            // index is out of bounds => NOT empty
+            return false;
+        }
+        if self.board.len() <= index.0 {
+            // line does not exist (yet) => empty
+            return true;
+        }
+
+        match self.board[index.0][index.1] {
+            Point::Air => true,
+            Point::Rock => false,
+        }
+    }
+
+    fn get_next_motion(&mut self) -> Motion {
+        let next_motion = self.motion_sequence[self.motion_index].clone();
+        self.motion_index = (self.motion_index + 1) % self.motion_sequence.len();
+        next_motion
+    }
+
+    // drops the next rock of the repeating shape sequence
+    pub fn drop_rock(&mut self) {
+        self.add_rock(&SHAPES[self.rock_counter as usize % SHAPES.len()]);
+    }
+
+    fn add_rock(&mut self, shape: &Shape) {
+        let mut index: (usize, usize) = (self.board.len() + 3, 2);
+
+        loop {
+            let motion = self.get_next_motion();
+
+            if let Some(next_index) = shape.is_motion_possible(self, index, motion) {
+                index = next_index;
+            }
+
+            match shape.is_motion_possible(self, index, Motion::Down) {
+                Some(next_index) => index = next_index,
+                None => {
+                    self.place_rock(shape, index);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn place_rock(&mut self, shape: &Shape, index: (usize, usize)) {
+        let indices = shape.get_indices(index);
+        for index in indices {
+            while index.0 >= self.board.len() {
+                self.board.push(vec![Point::Air; self.width]);
+            }
+
+            self.board[index.0][index.1] = Point::Rock;
+        }
+        self.rock_counter += 1;
+    }
+}
+
+impl Simulation for Cavern {
+    // the rocks keep falling forever
+    fn step(&mut self) -> bool {
+        self.drop_rock();
+        true
+    }
+
+    fn render(&self) -> String {
+        self.pretty_print()
+    }
+
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("rock_counter", self.rock_counter.to_string()),
+            ("height", self.height().to_string()),
+            ("motion_index", self.motion_index.to_string()),
+        ]
+    }
+}
+
+const SHAPES: [Shape; 5] = [
+    Shape::HorizontalLine,
+    Shape::Cross,
+    Shape::FlippedL,
+    Shape::VerticalLine,
+    Shape::Square,
+];
+
+// drops the rocks one by one until a repeating state is found, the rest is extrapolated
+fn tower_height(content: &str, width: usize, rocks: usize) -> Result<usize, Error> {
+    let mut cavern = Cavern::new(content, width)?;
+    let mut seen: HashMap<State, (usize, usize)> = HashMap::new();
+    let mut skipped_height = 0;
+
+    let mut i = 0;
+    while i < rocks {
+        cavern.drop_rock();
+        i += 1;
+
+        if skipped_height > 0 || cavern.height() < SURFACE_DEPTH {
+            continue;
+        }
+
+        let state = (i % SHAPES.len(), cavern.motion_index, cavern.surface());
+        if let Some((previous_i, previous_height)) = seen.insert(state, (i, cavern.height())) {
+            let cycle_length = i - previous_i;
+            let cycles = (rocks - i) / cycle_length;
+
+            i += cycles * cycle_length;
+            skipped_height = cycles * (cavern.height() - previous_height);
+        }
+    }
+
+    Ok(cavern.height() + skipped_height)
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    let rocks = match part {
+        Part::One => config.rocks,
+        Part::Two => PART_2_ROCKS,
+    };
+
+    Ok(tower_height(input, config.cavern_width, rocks)?.into())
+}
+
+// SAMPLE:
+// after the first million rocks, the height is 1514288
+// then each next sevent million rocks add 10600000
+// 1514288 + 10600000 * 142857 = 1 514 285 714 288
+
+// looking at input, first cycle of all inputs increases the height by 2802
+// each subsequent cycle then by 2754
+// 1748 + 574 712 642 * 1740 + 1172 = 1 000 000 000 000
+// that leaves the us to look at the final 1172 rocks, which add up to height of 1831
+// 2802 + 574 712 642 * 2754 + 1831 = 1 582 758 620 701
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn sample() {
+        assert_eq!(solve(SAMPLE, Part::One), Ok(Answer::Number(3068)));
+        assert_eq!(solve(SAMPLE, Part::Two), Ok(Answer::Number(1514285714288)));
+    }
+
+    // the skipped cycles have to land on the same height as dropping every rock
+    #[test]
+    fn cycles_match_dropping_every_rock() {
+        let mut cavern = Cavern::new(SAMPLE, 7).unwrap();
+        for rocks in 1..=5000 {
+            cavern.drop_rock();
+            if rocks % 97 == 0 || rocks == 5000 {
+                assert_eq!(
+                    tower_height(SAMPLE, 7, rocks),
+                    Ok(cavern.height()),
+                    "{rocks} rocks"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use aoc::{Answer, Error, Part};
+
+fn mix(input: &str, decryption_key: i64, mixing_iterations: usize) -> Result<i64, Error> {
+    let mut encrypted = input
+        .lines()
+        .enumerate()
+        .map(|(i, e)| {
+            e.parse::<i64>()
+                .map(|e| e * decryption_key)
+                .map_err(|_| Error::parse(i + 1, format!("Expected a number, got {e}")))
+        })
+        .collect::<Result<Vec<i64>, Error>>()?;
+    let mut positions = encrypted
+        .iter()
+        .enumerate()
+        .map(|e| e.0)
+        .collect::<Vec<usize>>();
+
+    let length = positions.len();
+    if length < 2 {
+        return Err(Error::parse(0, "Expected at least two numbers"));
+    }
+
+    for _ in 0..mixing_iterations {
+        for i in 0..length {
+            let position = positions.iter().position(|&e| e == i).unwrap();
+            let value = encrypted[position];
+            let mut next_position = position as i64 + value;
+
+            if next_position < 0 || next_position >= length as i64 {
+                next_position %= length as i64 - 1;
+                if next_position < 0 {
+                    next_position += length as i64 - 1;
+                }
+            }
+
+            move_element(&mut encrypted, position, next_position as usize);
+            move_element(&mut positions, position, next_position as usize);
+        }
+    }
+
+    let null_position = encrypted
+        .iter()
+        .position(|&e| e == 0)
+        .ok_or_else(|| Error::parse(0, "Missing 0"))?;
+
+    Ok(encrypted[(null_position + 1000) % length]
+        + encrypted[(null_position + 2000) % length]
+        + encrypted[(null_position + 3000) % length])
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let result = match part {
+        Part::One => mix(input, 1, 1)?,
+        Part::Two => mix(input, 811589153, 10)?,
+    };
+
+    Ok(result.into())
+}
+
+fn move_element<T>(array: &mut Vec<T>, from: usize, to: usize) {
+    if from == to {
+        return;
+    }
+    let element = array.remove(from);
+    array.insert(to, element);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let sample = include_str!("../sample");
+        assert_eq!(solve(sample, Part::One), Ok(Answer::Number(3)));
+        assert_eq!(solve(sample, Part::Two), Ok(Answer::Number(1623178306)));
+    }
+}
@@ -0,0 +1,191 @@
+use aoc::{Answer, Error, Part};
+use std::cmp::max;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    pub fn from(op: &str) -> Option<Self> {
+        match op {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Subtract),
+            "*" => Some(Self::Multiply),
+            "/" => Some(Self::Divide),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Job<'a> {
+    Yell(i64),
+    Calculate(&'a str, Operation, &'a str),
+    Human,
+}
+
+fn parse_monkeys(input: &str) -> Result<HashMap<&str, Job<'_>>, Error> {
+    let mut monkeys: HashMap<&str, Job> = HashMap::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let (monkey, calculation) = match line.split(": ").collect::<Vec<&str>>()[..] {
+            [monkey, calculation] => (monkey, calculation),
+            _ => return Err(Error::parse(i + 1, format!("Unexpected input {line}!"))),
+        };
+
+        let job = match calculation.split_whitespace().collect::<Vec<&str>>()[..] {
+            [number] => Job::Yell(
+                number
+                    .parse()
+                    .map_err(|_| Error::parse(i + 1, format!("Unexpected number {number}!")))?,
+            ),
+            [m1, operation, m2] => Job::Calculate(
+                m1,
+                Operation::from(operation).ok_or_else(|| {
+                    Error::parse(i + 1, format!("Could not parse operation {operation}!"))
+                })?,
+                m2,
+            ),
+            _ => {
+                return Err(Error::parse(
+                    i + 1,
+                    format!("Unexpected calculation {calculation}!"),
+                ))
+            }
+        };
+
+        monkeys.insert(monkey, job);
+    }
+
+    for job in monkeys.values() {
+        if let Job::Calculate(m1, _, m2) = job {
+            if !monkeys.contains_key(m1) || !monkeys.contains_key(m2) {
+                return Err(Error::parse(0, format!("Unknown monkey {m1} or {m2}!")));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    let mut monkeys = parse_monkeys(input)?;
+
+    if part == Part::Two {
+        *monkeys
+            .get_mut("humn")
+            .ok_or_else(|| Error::parse(0, "Missing the human!"))? = Job::Human;
+    }
+
+    let depths = calc_depths(&monkeys);
+
+    let mut sorted_depths = depths.keys().collect::<Vec<&i64>>();
+    sorted_depths.sort();
+    for depth in sorted_depths {
+        for monkey in &depths[depth] {
+            let result: Option<i64> = match &monkeys[monkey] {
+                Job::Yell(n) => Some(n.to_owned()),
+                Job::Human => None,
+                Job::Calculate(m1, operation, m2) => {
+                    let operand1 = match &monkeys[m1] {
+                        Job::Yell(n) => Some(n),
+                        _ => None,
+                    };
+                    let operand2 = match &monkeys[m2] {
+                        Job::Yell(n) => Some(n),
+                        _ => None,
+                    };
+
+                    match (operand1, operand2) {
+                        (Some(operand1), Some(operand2)) => Some(match operation {
+                            Operation::Add => operand1 + operand2,
+                            Operation::Subtract => operand1 - operand2,
+                            Operation::Multiply => operand1 * operand2,
+                            Operation::Divide => operand1 / operand2,
+                        }),
+                        _ => None,
+                    }
+                }
+            };
+
+            if let Some(number) = result {
+                *monkeys.get_mut(monkey).unwrap() = Job::Yell(number);
+            }
+        }
+    }
+
+    let root = monkeys
+        .get("root")
+        .ok_or_else(|| Error::parse(0, "Missing the root monkey!"))?;
+
+    match (part, root) {
+        (Part::One, Job::Yell(number)) => Ok((*number).into()),
+        (Part::Two, Job::Calculate(m1, _, m2)) => {
+            let result = match (&monkeys[m1], &monkeys[m2]) {
+                (Job::Yell(expected_number), Job::Calculate(_, _, _)) => {
+                    find_human(&monkeys, m2, *expected_number)
+                }
+                (Job::Calculate(_, _, _), Job::Yell(expected_number)) => {
+                    find_human(&monkeys, m1, *expected_number)
+                }
+                _ => None,
+            };
+            result
+                .map(|e| e.into())
+                .ok_or_else(|| Error::unsolvable("Unexpected state!"))
+        }
+        _ => Err(Error::unsolvable("Unexpected state!")),
+    }
+}
+
+fn calc_depths<'a>(monkeys: &HashMap<&'a str, Job>) -> HashMap<i64, Vec<&'a str>> {
+    let mut depths: HashMap<i64, Vec<&str>> = HashMap::new();
+
+    for &monkey in monkeys.keys() {
+        let depth = calc_depth(monkeys, monkey);
+        depths.entry(depth).or_default().push(monkey);
+    }
+
+    depths
+}
+
+fn calc_depth(monkeys: &HashMap<&str, Job>, monkey: &str) -> i64 {
+    match monkeys[monkey] {
+        Job::Yell(_) | Job::Human => 0,
+        Job::Calculate(m1, _, m2) => max(calc_depth(monkeys, m1), calc_depth(monkeys, m2)) + 1,
+    }
+}
+
+fn find_human<'a>(
+    monkeys: &'a HashMap<&'a str, Job>,
+    monkey: &'a str,
+    expected_number: i64,
+) -> Option<i64> {
+    let (first_monkey, operation, second_monkey) = match &monkeys[monkey] {
+        Job::Yell(_) => return None,
+        Job::Calculate(m1, operation, m2) => (m1, operation, m2),
+        Job::Human => return Some(expected_number),
+    };
+
+    match (&monkeys[first_monkey], &monkeys[second_monkey]) {
+        (Job::Yell(n), Job::Calculate(_, _, _)) | (Job::Yell(n), Job::Human) => match operation {
+            Operation::Add => find_human(monkeys, second_monkey, expected_number - n),
+            Operation::Subtract => find_human(monkeys, second_monkey, n - expected_number),
+            Operation::Multiply => find_human(monkeys, second_monkey, expected_number / n),
+            Operation::Divide => find_human(monkeys, second_monkey, n / expected_number),
+        },
+        (Job::Calculate(_, _, _), Job::Yell(n)) | (Job::Human, Job::Yell(n)) => match operation {
+            Operation::Add => find_human(monkeys, first_monkey, expected_number - n),
+            Operation::Subtract => find_human(monkeys, first_monkey, n + expected_number),
+            Operation::Multiply => find_human(monkeys, first_monkey, expected_number / n),
+            Operation::Divide => find_human(monkeys, first_monkey, n * expected_number),
+        },
+
+        _ => None,
+    }
+}
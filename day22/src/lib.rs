@@ -0,0 +1,439 @@
+use aoc::params::{ParamError, Params};
+use aoc::step::Simulation;
+use aoc::{Answer, Error, Part};
+use std::fmt;
+
+pub struct Config {
+    pub face_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { face_size: 50 }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &mut Params) -> Result<Self, ParamError> {
+        let default = Self::default();
+
+        let face_size = params.take("face_size", default.face_size)?;
+        if face_size == 0 {
+            return Err(ParamError::Invalid {
+                key: "face_size".to_string(),
+                value: face_size.to_string(),
+            });
+        }
+
+        Ok(Self { face_size })
+    }
+}
+
+fn parse_instructions(instructions_str: &str, line: usize) -> Result<Vec<Instruction>, Error> {
+    let mut instructions: Vec<Instruction> = vec![];
+    let mut current_number: Option<u32> = None;
+    for ch in instructions_str.trim_end().chars() {
+        match ch {
+            'R' => {
+                if let Some(number) = current_number {
+                    instructions.push(Instruction::Move(number));
+                    current_number = None;
+                };
+                instructions.push(Instruction::Turn(Turn::Clockwise));
+            }
+            'L' => {
+                if let Some(number) = current_number {
+                    instructions.push(Instruction::Move(number));
+                    current_number = None;
+                };
+                instructions.push(Instruction::Turn(Turn::Counterclockwise));
+            }
+            n => {
+                let digit = n
+                    .to_digit(10)
+                    .ok_or_else(|| Error::parse(line, format!("Unknown instruction {n}!")))?;
+                current_number = match current_number {
+                    Some(number) => Some(number * 10 + digit),
+                    None => Some(digit),
+                }
+            }
+        }
+    }
+    if let Some(number) = current_number {
+        instructions.push(Instruction::Move(number));
+    };
+
+    Ok(instructions)
+}
+
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    solve_with(input, part, &Config::default())
+}
+
+// only the cube wrapping of part 2 is implemented
+pub fn solve_with(input: &str, part: Part, config: &Config) -> Result<Answer, Error> {
+    if part == Part::One {
+        return Err(Error::Unsupported(part));
+    }
+
+    let mut walk = Walk::new(input, config.face_size)?;
+    while walk.advance().map_err(Error::unsolvable)? {}
+
+    Ok(walk.map.calculate_password().into())
+}
+
+// the map walked one turn or one tile at a time
+pub struct Walk {
+    map: Map,
+    instructions: Vec<Instruction>,
+    next_instruction: usize,
+    remaining_tiles: u32,
+    error: Option<String>,
+}
+
+impl Walk {
+    pub fn new(input: &str, face_size: usize) -> Result<Self, Error> {
+        let (map, instructions_str) = match input.split("\n\n").collect::<Vec<&str>>()[..] {
+            [map, instructions] => (map, instructions),
+            _ => return Err(Error::parse(0, "Invalid input!")),
+        };
+
+        let map = Map::new(map, face_size)?;
+        let instructions = parse_instructions(instructions_str, map.points.len() + 2)?;
+
+        Ok(Self {
+            map,
+            instructions,
+            next_instruction: 0,
+            remaining_tiles: 0,
+            error: None,
+        })
+    }
+
+    // returns `Ok(false)` once all instructions are processed
+    pub fn advance(&mut self) -> Result<bool, String> {
+        if self.remaining_tiles == 0 {
+            let Some(instruction) = self.instructions.get(self.next_instruction) else {
+                return Ok(false);
+            };
+            self.next_instruction += 1;
+
+            match instruction {
+                Instruction::Turn(turn) => {
+                    self.map.direction = self.map.direction.turn(turn);
+                    return Ok(true);
+                }
+                Instruction::Move(0) => return Ok(true),
+                Instruction::Move(number) => self.remaining_tiles = *number,
+            }
+        }
+
+        self.remaining_tiles -= 1;
+        if !self.map.move_forward()? {
+            // hit a wall, the rest of the move is lost
+            self.remaining_tiles = 0;
+        }
+
+        Ok(true)
+    }
+}
+
+// writes the puzzle input back - the map without the player and the path
+impl fmt::Display for Walk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.map.points {
+            let row = row.iter().map(|e| e.to_string()).collect::<String>();
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        writeln!(f)?;
+
+        for instruction in &self.instructions {
+            write!(f, "{instruction}")?;
+        }
+        writeln!(f)
+    }
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> bool {
+        match self.advance() {
+            Ok(more) => more,
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        self.map.pretty_print()
+    }
+
+    fn inspect(&self) -> Vec<(&'static str, String)> {
+        let mut values = vec![
+            ("instruction", self.next_instruction.to_string()),
+            ("position", format!("{:?}", self.map.position)),
+            ("direction", self.map.direction.to_string()),
+            ("password", self.map.calculate_password().to_string()),
+        ];
+        if let Some(error) = &self.error {
+            values.push(("error", error.clone()));
+        }
+        values
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum Point {
+    Nothing,
+    Open,
+    Solid,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Point::Nothing => write!(f, " "),
+            Point::Open => write!(f, "."),
+            Point::Solid => write!(f, "#"),
+        }
+    }
+}
+
+enum Instruction {
+    Move(u32),
+    Turn(Turn),
+}
+
+enum Turn {
+    Clockwise,
+    Counterclockwise,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Move(number) => write!(f, "{number}"),
+            Instruction::Turn(Turn::Clockwise) => write!(f, "R"),
+            Instruction::Turn(Turn::Counterclockwise) => write!(f, "L"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Right => write!(f, ">"),
+            Direction::Down => write!(f, "v"),
+            Direction::Left => write!(f, "<"),
+            Direction::Up => write!(f, "^"),
+        }
+    }
+}
+
+impl Direction {
+    pub fn turn(&self, turn: &Turn) -> Self {
+        match turn {
+            Turn::Clockwise => match self {
+                Self::Right => Self::Down,
+                Self::Down => Self::Left,
+                Self::Left => Self::Up,
+                Self::Up => Self::Right,
+            },
+            Turn::Counterclockwise => match self {
+                Self::Right => Self::Up,
+                Self::Down => Self::Right,
+                Self::Left => Self::Down,
+                Self::Up => Self::Left,
+            },
+        }
+    }
+
+    pub fn delta(&self) -> (i32, i32) {
+        match self {
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Up => (-1, 0),
+        }
+    }
+}
+
+struct Map {
+    points: Vec<Vec<Point>>,
+    position: (usize, usize),
+    direction: Direction,
+    face_size: usize,
+}
+
+impl Map {
+    pub fn new(map: &str, face_size: usize) -> Result<Self, Error> {
+        let width = map.lines().map(|e| e.len()).max().unwrap_or(0);
+        let mut points = vec![];
+
+        for (line_number, line) in map.lines().enumerate() {
+            let mut row = vec![Point::Nothing; width];
+
+            for (i, ch) in line.chars().enumerate() {
+                row[i] = match ch {
+                    ' ' => Point::Nothing,
+                    '.' => Point::Open,
+                    '#' => Point::Solid,
+                    _ => {
+                        return Err(Error::parse(
+                            line_number + 1,
+                            format!("Unknown map point {ch}!"),
+                        ))
+                    }
+                };
+            }
+
+            points.push(row);
+        }
+
+        let player_position = points
+            .first()
+            .and_then(|row| row.iter().position(|e| *e == Point::Open))
+            .ok_or_else(|| Error::parse(1, "No starting position!"))?;
+
+        Ok(Self {
+            points,
+            position: (0, player_position),
+            direction: Direction::Right,
+            face_size,
+        })
+    }
+
+    pub fn pretty_print(&self) -> String {
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, e)| {
+                        if i == self.position.0 && j == self.position.1 {
+                            self.direction.to_string()
+                        } else {
+                            e.to_string()
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    // moves a single tile forward, returns `false` when a wall is in the way
+    pub fn move_forward(&mut self) -> Result<bool, String> {
+        let (next_position, next_direction) = self
+            .get_next_position(self.position)
+            .ok_or("The map does not match the cube layout!")?;
+
+        match self
+            .points
+            .get(next_position.0)
+            .and_then(|row| row.get(next_position.1))
+        {
+            None | Some(Point::Nothing) => {
+                return Err("The map does not match the cube layout!".to_string())
+            }
+            Some(Point::Open) => {}
+            Some(Point::Solid) => return Ok(false),
+        }
+
+        self.position = next_position;
+        self.direction = next_direction;
+
+        Ok(true)
+    }
+
+    fn get_next_position(&self, position: (usize, usize)) -> Option<((usize, usize), Direction)> {
+        // the cube layout is hardcoded for the input, only the face size can change
+        let f = self.face_size;
+        let delta = self.direction.delta();
+        let next_position = (position.0 as i32 + delta.0, position.1 as i32 + delta.1);
+
+        if next_position.0 < 0 {
+            let col = next_position.1 as usize;
+            match col / f {
+                1 => {
+                    return Some(((3 * f + (col - f), 0), Direction::Right));
+                }
+                2 => {
+                    return Some(((4 * f - 1, col - 2 * f), Direction::Up));
+                }
+                _ => return None,
+            }
+        }
+        if next_position.1 < 0 {
+            let row = next_position.0 as usize;
+            match row / f {
+                2 => {
+                    return Some(((f - (row % f) - 1, f), Direction::Right));
+                }
+                3 => {
+                    return Some(((0, f + row % f), Direction::Down));
+                }
+                _ => return None,
+            }
+        }
+
+        let (row, col) = (next_position.0 as usize, next_position.1 as usize);
+
+        match (row / f, col / f, &self.direction) {
+            (4, 0, Direction::Down) if row == 4 * f => {
+                return Some(((0, 2 * f + col), Direction::Down));
+            }
+            (0, 3, Direction::Right) if col == 3 * f => {
+                return Some(((2 * f + (f - 1 - row), 2 * f - 1), Direction::Left));
+            }
+            (0, 0, Direction::Left) if col == f - 1 => {
+                return Some(((2 * f + (f - 1 - row), 0), Direction::Right));
+            }
+            (1, 0, Direction::Left) if col == f - 1 => {
+                return Some(((2 * f, row - f), Direction::Down));
+            }
+            (1, 0, Direction::Up) if row == 2 * f - 1 => {
+                return Some(((f + col, f), Direction::Right));
+            }
+            (1, 2, Direction::Down) if row == f => {
+                return Some(((f + (col - 2 * f), 2 * f - 1), Direction::Left));
+            }
+            (1, 2, Direction::Right) if col == 2 * f => {
+                return Some(((f - 1, 2 * f + (row - f)), Direction::Up));
+            }
+            (2, 2, Direction::Right) if col == 2 * f => {
+                return Some(((f - 1 - (row - 2 * f), 3 * f - 1), Direction::Left));
+            }
+            (3, 1, Direction::Down) if row == 3 * f => {
+                return Some(((3 * f + (col - f), f - 1), Direction::Left));
+            }
+            (3, 1, Direction::Right) if col == f => {
+                return Some(((3 * f - 1, f + (row - 3 * f)), Direction::Up));
+            }
+            _ => {}
+        }
+
+        Some(((row, col), self.direction.clone()))
+    }
+
+    pub fn calculate_password(&self) -> usize {
+        let direction = match self.direction {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+
+        (self.position.0 + 1) * 1000 + (self.position.1 + 1) * 4 + direction
+    }
+}
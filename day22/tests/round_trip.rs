@@ -0,0 +1,11 @@
+use day22::Walk;
+
+const SAMPLE: &str = include_str!("../sample");
+
+// the map and the path are written back as a file, ending in a newline the bundled sample lacks
+#[test]
+fn map_and_path_round_trip() {
+    let input = format!("{SAMPLE}\n");
+    assert_eq!(Walk::new(&input, 4).unwrap().to_string(), input);
+    assert_eq!(Walk::new(SAMPLE, 4).unwrap().to_string(), input);
+}
@@ -0,0 +1,96 @@
+use aoc::{Answer, Error, Part};
+use std::fmt;
+use std::str::FromStr;
+
+// a number in the balanced base 5 used by the fuel requirements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snafu(pub i64);
+
+impl FromStr for Snafu {
+    type Err = String;
+
+    fn from_str(snafu: &str) -> Result<Self, Self::Err> {
+        let mut result = 0;
+        let mut multiplier = 1;
+        for ch in snafu.chars().rev() {
+            let number = match ch {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(format!("Unknown digit {ch}!")),
+            };
+
+            result += number * multiplier;
+            multiplier *= 5;
+        }
+
+        Ok(Snafu(result))
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+
+        let mut base10 = self.0;
+        let mut result: Vec<char> = vec![];
+
+        while base10 != 0 {
+            // the digit in -2..=2 with the same remainder
+            let digit = (base10 + 2).rem_euclid(5) - 2;
+
+            result.push(match digit {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                _ => '=',
+            });
+            base10 = (base10 - digit) / 5;
+        }
+
+        write!(f, "{}", result.iter().rev().collect::<String>())
+    }
+}
+
+// the puzzle input, one number per line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuelRequirements {
+    pub numbers: Vec<Snafu>,
+}
+
+impl FuelRequirements {
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut numbers = vec![];
+        for (i, line) in input.lines().enumerate() {
+            numbers.push(line.parse::<Snafu>().map_err(|e| Error::parse(i + 1, e))?);
+        }
+
+        Ok(Self { numbers })
+    }
+}
+
+impl fmt::Display for FuelRequirements {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for number in &self.numbers {
+            writeln!(f, "{number}")?;
+        }
+        Ok(())
+    }
+}
+
+// there is no second puzzle on the last day
+pub fn solve(input: &str, part: Part) -> Result<Answer, Error> {
+    if part == Part::Two {
+        return Err(Error::Unsupported(part));
+    }
+
+    let requirements = FuelRequirements::parse(input)?;
+    let sum = requirements.numbers.iter().map(|e| e.0).sum();
+
+    Ok(Snafu(sum).to_string().into())
+}
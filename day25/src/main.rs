@@ -1,53 +1,7 @@
+use aoc::{Error, Part};
 use std::env;
 use std::fs;
-
-fn snafu_to_base_10(snafu: &str) -> i64 {
-    let mut result = 0;
-    let mut multiplier = 1;
-    for ch in snafu.chars().rev() {
-        let number = match ch {
-            '2' => 2,
-            '1' => 1,
-            '0' => 0,
-            '-' => -1,
-            '=' => -2,
-            _ => unimplemented!("Unknown digit {ch}!"),
-        };
-
-        result += number * multiplier;
-        multiplier *= 5;
-    }
-
-    result
-}
-
-fn base_10_to_snafu(base10: i64) -> String {
-    if base10 == -2 {
-        return "=".to_owned();
-    }
-
-    let mut base10 = base10;
-    let mut result: Vec<char> = vec![];
-
-    while base10 != 0 {
-        let modulo = base10 % 5;
-
-        let (digit, carry) = match modulo {
-            4 => ('-', 1),
-            3 => ('=', 2),
-            2 => ('2', 0),
-            1 => ('1', 0),
-            0 => ('0', 0),
-            _ => unimplemented!("Unknown state {modulo}!"),
-        };
-
-        result.push(digit);
-        base10 += carry;
-        base10 /= 5;
-    }
-
-    result.iter().rev().collect()
-}
+use std::process;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -57,10 +11,19 @@ fn main() {
 
     let content = fs::read_to_string(input_file_path).expect("Could not read the input file!");
 
-    let mut numbers = vec![];
-    for line in content.lines() {
-        numbers.push(snafu_to_base_10(line));
+    let mut failed = false;
+    for part in [Part::One, Part::Two] {
+        match day25::solve(&content, part) {
+            Ok(answer) => println!("{part}: {answer}"),
+            // a part without a solution says nothing about the input
+            Err(e @ Error::Unsupported(_)) => println!("{part}: {e}"),
+            Err(e) => {
+                eprintln!("{part}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
     }
-
-    println!("part 1: {}", base_10_to_snafu(numbers.iter().sum()));
 }
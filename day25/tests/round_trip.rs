@@ -0,0 +1,18 @@
+use day25::{FuelRequirements, Snafu};
+
+const SAMPLE: &str = include_str!("../sample");
+
+// the numbers are written back as a file, ending in a newline the bundled sample lacks
+#[test]
+fn requirements_round_trip() {
+    let input = format!("{SAMPLE}\n");
+    assert_eq!(FuelRequirements::parse(&input).unwrap().to_string(), input);
+    assert_eq!(FuelRequirements::parse(SAMPLE).unwrap().to_string(), input);
+}
+
+#[test]
+fn snafu_round_trips() {
+    for snafu in ["0", "1", "2", "1=", "1-", "10", "2=-01", "1121-1110-1=0"] {
+        assert_eq!(snafu.parse::<Snafu>().unwrap().to_string(), snafu);
+    }
+}